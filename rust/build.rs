@@ -0,0 +1,134 @@
+//! Codegen: turns `i18n/locales/*.ftl` into typed accessors in `i18n::keys`.
+//!
+//! Every translation key is otherwise looked up by a bare `&str` (`i18n.t("welcome")`),
+//! so a typo or a key missing from one language silently falls through to the raw key
+//! (or the Japanese fallback) at runtime instead of failing the build. This script reads
+//! the resource files, fails the build if any language is missing a key another one
+//! defines, and emits one `pub fn` per key into `OUT_DIR/i18n_keys.rs` whose parameters
+//! mirror that key's `{placeholder}`s, included into `i18n::keys` by `src/i18n/mod.rs`.
+//!
+//! Kept deliberately separate from the `i18n` module's own (richer) resource-file
+//! parser: build scripts compile and run before the crate they belong to, so they can't
+//! borrow its code.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const LANGUAGES: [&str; 4] = ["ja", "en", "zh", "ko"];
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let locales_dir = Path::new(&manifest_dir).join("i18n/locales");
+    println!("cargo:rerun-if-changed={}", locales_dir.display());
+
+    // lang_code -> (key -> value)
+    let mut tables: BTreeMap<&str, BTreeMap<String, String>> = BTreeMap::new();
+    for &lang in &LANGUAGES {
+        let path = locales_dir.join(format!("{lang}.ftl"));
+        let content = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("i18n codegen: failed to read {}: {e}", path.display()));
+        tables.insert(lang, parse_ftl(&content));
+    }
+
+    let all_keys: BTreeSet<&String> = tables.values().flat_map(|t| t.keys()).collect();
+    for key in &all_keys {
+        let missing: Vec<&str> = LANGUAGES
+            .iter()
+            .copied()
+            .filter(|lang| !tables[lang].contains_key(key.as_str()))
+            .collect();
+        if !missing.is_empty() {
+            panic!(
+                "i18n codegen: key `{key}` is missing from language file(s) {missing:?} \
+                 (i18n/locales/*.ftl must define the same key set in every language)"
+            );
+        }
+    }
+
+    let mut generated = String::new();
+    generated.push_str("// @generated by build.rs from i18n/locales/*.ftl. Do not edit by hand.\n\n");
+    for key in &all_keys {
+        // Placeholder shape must agree across languages too, since it's baked into the
+        // generated function signature; use English's template as the reference.
+        let placeholders = placeholders_in(&tables["en"][key.as_str()]);
+        generated.push_str(&render_accessor(key, &placeholders));
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    let dest = Path::new(&out_dir).join("i18n_keys.rs");
+    fs::write(&dest, generated).unwrap_or_else(|e| panic!("i18n codegen: failed to write {}: {e}", dest.display()));
+}
+
+/// Parse `key = value` lines, `#`-comments and blank lines ignored, matching
+/// `i18n::load_resource_file`'s format exactly so both read the same `.ftl` files.
+fn parse_ftl(content: &str) -> BTreeMap<String, String> {
+    let mut map = BTreeMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            map.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    map
+}
+
+/// Extract the named/positional placeholders referenced by a `{...}` template, in
+/// first-seen order. A `plural`/`select` block (`{name, plural, ...}`) contributes only
+/// its leading `name`; a nested `#` inside such a block refers to that same argument, not
+/// a placeholder of its own.
+fn placeholders_in(template: &str) -> Vec<String> {
+    let chars: Vec<char> = template.chars().collect();
+    let mut names = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '{' {
+            let mut depth = 1;
+            let start = i + 1;
+            let mut j = start;
+            while j < chars.len() && depth > 0 {
+                match chars[j] {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => {}
+                }
+                j += 1;
+            }
+            let inner: String = chars[start..j.saturating_sub(1)].iter().collect();
+            let name = inner.split(',').next().unwrap_or("").trim().to_string();
+            if !name.is_empty() && !names.contains(&name) {
+                names.push(name);
+            }
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+    names
+}
+
+/// Render one `i18n::keys::<key>` accessor. Zero-placeholder keys are a direct lookup;
+/// keys with placeholders take one `&str` argument per placeholder (in template order)
+/// and route through `I18n::tf_named`, so a caller missing or misnaming an argument is a
+/// compile error instead of a silently-unsubstituted `{placeholder}` at runtime.
+fn render_accessor(key: &str, placeholders: &[String]) -> String {
+    if placeholders.is_empty() {
+        format!(
+            "pub fn {key}(i18n: &crate::i18n::I18n) -> String {{\n    i18n.t(\"{key}\").to_string()\n}}\n\n"
+        )
+    } else {
+        let params: String = placeholders.iter().map(|p| format!(", {p}: &str")).collect();
+        let args: String = placeholders
+            .iter()
+            .map(|p| format!("(\"{p}\", {p})"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "pub fn {key}(i18n: &crate::i18n::I18n{params}) -> String {{\n    i18n.tf_named(\"{key}\", &[{args}])\n}}\n\n"
+        )
+    }
+}
@@ -5,8 +5,10 @@
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_int};
 
-use crate::rag::{DocType, Document, Retriever};
-use crate::tokenizer::{MultiLanguageTokenizer, Tokenizer};
+use crate::normalize::Normalizer;
+use crate::rag::{DocType, DocTypeClassifier, Document, Retriever, SearchMode};
+use crate::tokenizer::kanji::{self, KanjiTable};
+use crate::tokenizer::{JapaneseTokenizer, MultiLanguageTokenizer, PorterStemmer, Stemmer, Tokenizer};
 
 /// Tokenize text and return JSON array of tokens
 ///
@@ -69,6 +71,19 @@ pub unsafe extern "C" fn novelist_retriever_free(retriever: *mut Retriever) {
     }
 }
 
+/// Map a `doc_type` string (as used by `novelist_retriever_add`/`novelist_classifier_train`)
+/// to its `DocType`, defaulting to `DocType::Other` for anything unrecognized.
+fn parse_doc_type(s: &str) -> DocType {
+    match s {
+        "bible" => DocType::Bible,
+        "character" => DocType::Character,
+        "fact" => DocType::Fact,
+        "chapter" => DocType::Chapter,
+        "scene_spec" => DocType::SceneSpec,
+        _ => DocType::Other,
+    }
+}
+
 /// Add document to retriever
 ///
 /// # Safety
@@ -101,13 +116,7 @@ pub unsafe extern "C" fn novelist_retriever_add(
         DocType::Other
     } else {
         let type_str = unsafe { CStr::from_ptr(doc_type).to_string_lossy() };
-        match type_str.as_ref() {
-            "bible" => DocType::Bible,
-            "character" => DocType::Character,
-            "fact" => DocType::Fact,
-            "chapter" => DocType::Chapter,
-            _ => DocType::Other,
-        }
+        parse_doc_type(&type_str)
     };
 
     let doc = Document {
@@ -156,6 +165,285 @@ pub unsafe extern "C" fn novelist_retriever_search(
     results.len() as c_int
 }
 
+/// Create a new (untrained) DocType classifier
+#[no_mangle]
+pub extern "C" fn novelist_classifier_new() -> *mut DocTypeClassifier {
+    Box::into_raw(Box::new(DocTypeClassifier::new()))
+}
+
+/// Free a classifier
+///
+/// # Safety
+/// `classifier` must be a pointer returned by `novelist_classifier_new` and must not be freed twice.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_classifier_free(classifier: *mut DocTypeClassifier) {
+    if !classifier.is_null() {
+        unsafe {
+            let _ = Box::from_raw(classifier);
+        }
+    }
+}
+
+/// Classify `text` with a trained classifier, returning `{"doc_type": "...", "confidence": 0.0}`
+/// as JSON, or an empty-object string if the classifier isn't trained yet.
+///
+/// # Safety
+/// `classifier` must be a valid pointer from `novelist_classifier_new`.
+/// `text` must be a valid, non-null, NUL-terminated C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_classify(
+    classifier: *mut DocTypeClassifier,
+    text: *const c_char,
+) -> *mut c_char {
+    if classifier.is_null() || text.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let classifier = unsafe { &*classifier };
+    let text_str = unsafe { CStr::from_ptr(text).to_string_lossy() };
+
+    let json = match classifier.classify(&text_str) {
+        Some((doc_type, confidence)) => {
+            serde_json::json!({ "doc_type": doc_type, "confidence": confidence }).to_string()
+        }
+        None => "{}".to_string(),
+    };
+
+    match CString::new(json) {
+        Ok(cstring) => cstring.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Train (or continue training) a classifier on labeled documents, given as JSON
+/// `[["content", "doc_type"], ...]` using the same `doc_type` strings as
+/// `novelist_retriever_add`. Returns the number of examples trained on, or `-1` if
+/// `labeled_docs_json` isn't valid UTF-8/JSON.
+///
+/// # Safety
+/// `classifier` must be a valid pointer from `novelist_classifier_new`.
+/// `labeled_docs_json` must be a valid, non-null, NUL-terminated C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_classifier_train(
+    classifier: *mut DocTypeClassifier,
+    labeled_docs_json: *const c_char,
+) -> c_int {
+    if classifier.is_null() || labeled_docs_json.is_null() {
+        return -1;
+    }
+
+    let classifier = unsafe { &mut *classifier };
+    let json_str = match unsafe { CStr::from_ptr(labeled_docs_json) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return -1,
+    };
+
+    let pairs: Vec<(String, String)> = match serde_json::from_str(json_str) {
+        Ok(pairs) => pairs,
+        Err(_) => return -1,
+    };
+
+    let labeled: Vec<(&str, DocType)> = pairs
+        .iter()
+        .map(|(content, doc_type)| (content.as_str(), parse_doc_type(doc_type)))
+        .collect();
+
+    classifier.train(&labeled);
+    labeled.len() as c_int
+}
+
+/// Attach a trained classifier to `retriever`, so `novelist_retriever_add` auto-predicts
+/// `doc_type` for documents added with `doc_type = "other"`/null from then on. Consumes
+/// `classifier` — after this call the retriever owns it, so the pointer must not be
+/// freed (via `novelist_classifier_free`) or reused.
+///
+/// # Safety
+/// `retriever` must be a valid pointer from `novelist_retriever_new`.
+/// `classifier` must be a valid pointer from `novelist_classifier_new`, not previously
+/// passed to this function or to `novelist_classifier_free`.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_classifier_attach(
+    retriever: *mut Retriever,
+    classifier: *mut DocTypeClassifier,
+) {
+    if retriever.is_null() || classifier.is_null() {
+        return;
+    }
+
+    let retriever = unsafe { &*retriever };
+    let classifier = unsafe { Box::from_raw(classifier) };
+    retriever.set_classifier(*classifier);
+}
+
+/// Search retriever, returning JSON `[{"id", "score", "rank"}, ...]`.
+///
+/// `mode` selects the ranking signal: `0` = dense cosine, `1` = lexical BM25,
+/// `2` = hybrid (Reciprocal Rank Fusion of both). Unrecognized values fall back to dense.
+///
+/// # Safety
+/// `retriever` must be a valid pointer from `novelist_retriever_new`.
+/// `query` must be a valid, non-null, NUL-terminated C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_retriever_search_json(
+    retriever: *mut Retriever,
+    query: *const c_char,
+    top_k: c_int,
+    mode: c_int,
+) -> *mut c_char {
+    if retriever.is_null() || query.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let retriever = unsafe { &*retriever };
+    let query_str = unsafe { CStr::from_ptr(query).to_string_lossy() };
+
+    let search_mode = match mode {
+        1 => SearchMode::Lexical,
+        2 => SearchMode::Hybrid,
+        _ => SearchMode::Dense,
+    };
+
+    let results = retriever.search_with_mode(&query_str, top_k as usize, search_mode);
+    let json_results: Vec<_> = results
+        .iter()
+        .map(|r| serde_json::json!({ "id": r.doc.id, "score": r.score, "rank": r.rank }))
+        .collect();
+
+    let json = serde_json::to_string(&json_results).unwrap_or_default();
+
+    match CString::new(json) {
+        Ok(cstring) => cstring.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Fuzzy search over the retriever's BM25 index, returning JSON
+/// `[{"id", "score", "rank"}, ...]`. `max_edits` bounds the Levenshtein distance used
+/// to expand each query term; `prefix` (non-zero) matches vocabulary terms by in-budget
+/// prefix instead of requiring a full match, for autocomplete-style lookups.
+///
+/// # Safety
+/// `retriever` must be a valid pointer from `novelist_retriever_new`.
+/// `query` must be a valid, non-null, NUL-terminated C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_retriever_search_fuzzy(
+    retriever: *mut Retriever,
+    query: *const c_char,
+    top_k: c_int,
+    max_edits: c_int,
+    prefix: c_int,
+) -> *mut c_char {
+    if retriever.is_null() || query.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let retriever = unsafe { &*retriever };
+    let query_str = unsafe { CStr::from_ptr(query).to_string_lossy() };
+
+    let results = retriever.search_fuzzy(
+        &query_str,
+        top_k as usize,
+        max_edits.max(0) as usize,
+        prefix != 0,
+    );
+    let json_results: Vec<_> = results
+        .iter()
+        .map(|r| serde_json::json!({ "id": r.doc.id, "score": r.score, "rank": r.rank }))
+        .collect();
+
+    let json = serde_json::to_string(&json_results).unwrap_or_default();
+
+    match CString::new(json) {
+        Ok(cstring) => cstring.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Tokenize Japanese text and return furigana pairs as JSON `[["surface", "reading"], ...]`,
+/// using the bundled kanji table, so the Go side can render ruby text.
+///
+/// # Safety
+/// `text` must be a valid, non-null, NUL-terminated C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_furigana(text: *const c_char) -> *mut c_char {
+    if text.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let text_str = match unsafe { CStr::from_ptr(text) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let tokenizer = JapaneseTokenizer::new();
+    let tokens = tokenizer.tokenize(text_str);
+    let table = KanjiTable::bundled();
+    let infos = kanji::enrich(&tokens, &table);
+    let pairs = kanji::furigana(&infos);
+
+    let json = serde_json::to_string(&pairs).unwrap_or_default();
+
+    match CString::new(json) {
+        Ok(cstring) => cstring.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Normalize text with `Normalizer::new()` (NFKC, lowercase) plus diacritic stripping
+/// and kana/romaji folding switched on; transliteration stays off.
+///
+/// # Safety
+/// `text` must be a valid, non-null, NUL-terminated C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_normalize(text: *const c_char) -> *mut c_char {
+    if text.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let text_str = match unsafe { CStr::from_ptr(text) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let normalizer = Normalizer::new()
+        .with_strip_diacritics(true)
+        .with_kana_romaji_fold(true);
+    let normalized = normalizer.normalize(text_str);
+
+    match CString::new(normalized) {
+        Ok(cstring) => cstring.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Stem an English word (Porter algorithm), returning the stem unchanged for words that
+/// don't further reduce (including CJK text, where suffix-stripping doesn't apply).
+///
+/// # Safety
+/// `word` must be a valid, non-null, NUL-terminated C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn novelist_stem(word: *const c_char) -> *mut c_char {
+    if word.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let word_str = match unsafe { CStr::from_ptr(word) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let stemmed = if crate::normalize::contains_cjk(word_str) {
+        word_str.to_string()
+    } else {
+        PorterStemmer.stem(word_str)
+    };
+
+    match CString::new(stemmed) {
+        Ok(cstring) => cstring.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
 /// Get version
 #[no_mangle]
 pub extern "C" fn novelist_version() -> *const c_char {
@@ -181,6 +469,104 @@ mod tests {
         unsafe { novelist_free_string(out_ptr) };
     }
 
+    #[test]
+    fn test_ffi_normalize() {
+        let input = CString::new("café").expect("valid c string");
+        let out_ptr = unsafe { novelist_normalize(input.as_ptr()) };
+        assert!(!out_ptr.is_null());
+
+        let out = unsafe { CStr::from_ptr(out_ptr) }.to_string_lossy().into_owned();
+        assert_eq!(out, "cafe");
+
+        unsafe { novelist_free_string(out_ptr) };
+    }
+
+    #[test]
+    fn test_ffi_furigana() {
+        let input = CString::new("日本").expect("valid c string");
+        let out_ptr = unsafe { novelist_furigana(input.as_ptr()) };
+        assert!(!out_ptr.is_null());
+
+        let out = unsafe { CStr::from_ptr(out_ptr) }.to_string_lossy().into_owned();
+        assert!(out.starts_with('['));
+
+        unsafe { novelist_free_string(out_ptr) };
+    }
+
+    #[test]
+    fn test_ffi_classifier_lifecycle() {
+        let classifier = novelist_classifier_new();
+        assert!(!classifier.is_null());
+
+        let text = CString::new("a brave knight and his sword").expect("valid c string");
+        let out_ptr = unsafe { novelist_classify(classifier, text.as_ptr()) };
+        assert!(!out_ptr.is_null());
+
+        // Untrained classifier returns an empty object.
+        let out = unsafe { CStr::from_ptr(out_ptr) }.to_string_lossy().into_owned();
+        assert_eq!(out, "{}");
+
+        unsafe {
+            novelist_free_string(out_ptr);
+            novelist_classifier_free(classifier);
+        }
+    }
+
+    #[test]
+    fn test_ffi_classifier_train_and_attach_drives_auto_classification_at_add_time() {
+        let classifier = novelist_classifier_new();
+        assert!(!classifier.is_null());
+
+        let labeled_docs = CString::new(
+            r#"[["a brave knight named Theo wields a sword", "character"],
+                ["Elara is a young mage with silver hair", "character"],
+                ["the kingdom of Eldoria was founded a thousand years ago", "bible"],
+                ["the laws of magic require a blood sacrifice", "bible"]]"#,
+        )
+        .expect("valid c string");
+        let trained = unsafe { novelist_classifier_train(classifier, labeled_docs.as_ptr()) };
+        assert_eq!(trained, 4);
+
+        let retriever = novelist_retriever_new(64);
+        assert!(!retriever.is_null());
+        unsafe { novelist_classifier_attach(retriever, classifier) };
+
+        let id = CString::new("doc1").expect("valid c string");
+        let content = CString::new("Mira is a cheerful healer from the northern village")
+            .expect("valid c string");
+        unsafe {
+            // No `doc_type` passed, so `novelist_retriever_add` should fall back to the
+            // now-attached classifier instead of leaving it as `DocType::Other`.
+            novelist_retriever_add(
+                retriever,
+                id.as_ptr(),
+                content.as_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+            );
+        }
+
+        // If `novelist_retriever_add` ran the attached classifier, "doc1" is filed under
+        // `DocType::Character` even though no `doc_type` was passed at add time.
+        let retriever_ref = unsafe { &*retriever };
+        let by_type = retriever_ref.search_by_type("Mira", DocType::Character, 5);
+        assert!(by_type.iter().any(|r| r.doc.id == "doc1"));
+
+        unsafe { novelist_retriever_free(retriever) };
+    }
+
+    #[test]
+    fn test_ffi_stem() {
+        let input = CString::new("running").expect("valid c string");
+        let out_ptr = unsafe { novelist_stem(input.as_ptr()) };
+        assert!(!out_ptr.is_null());
+
+        let out = unsafe { CStr::from_ptr(out_ptr) }.to_string_lossy().into_owned();
+        assert_eq!(out, "run");
+
+        unsafe { novelist_free_string(out_ptr) };
+    }
+
     #[test]
     fn test_ffi_retriever_lifecycle() {
         let retriever = novelist_retriever_new(64);
@@ -206,6 +592,12 @@ mod tests {
         let count = unsafe { novelist_retriever_search(retriever, query.as_ptr(), 5) };
         assert!(count >= 1);
 
+        let json_ptr = unsafe { novelist_retriever_search_json(retriever, query.as_ptr(), 5, 2) };
+        assert!(!json_ptr.is_null());
+        let json = unsafe { CStr::from_ptr(json_ptr) }.to_string_lossy().into_owned();
+        assert!(json.contains("doc1"));
+        unsafe { novelist_free_string(json_ptr) };
+
         unsafe { novelist_retriever_free(retriever) };
     }
 }
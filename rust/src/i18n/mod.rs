@@ -7,6 +7,7 @@
 //! - Korean (ko)
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 /// Supported languages
@@ -19,16 +20,55 @@ pub enum Language {
 }
 
 impl Language {
+    /// Parse a BCP-47 language tag (e.g. `zh-Hans-CN`, `en_US`, case-insensitive,
+    /// `-` or `_` separators) by taking its primary language subtag and ignoring any
+    /// script/region/variant suffixes, rather than requiring an exact match against a
+    /// fixed list of tags. A handful of human-readable names (`"日本語"`, `"english"`,
+    /// ...) are matched first for backward compatibility with non-BCP-47 callers.
     pub fn from_code(code: &str) -> Option<Self> {
         match code {
-            "ja" | "ja-JP" | "日本語" => Some(Language::Japanese),
-            "en" | "en-US" | "en-GB" | "english" => Some(Language::English),
-            "zh" | "zh-CN" | "zh-TW" | "中文" => Some(Language::Chinese),
-            "ko" | "ko-KR" | "한국어" => Some(Language::Korean),
+            "日本語" => return Some(Language::Japanese),
+            "english" => return Some(Language::English),
+            "中文" => return Some(Language::Chinese),
+            "한국어" => return Some(Language::Korean),
+            _ => {}
+        }
+
+        let primary = code.split(['-', '_']).next()?.to_lowercase();
+        match primary.as_str() {
+            "ja" => Some(Language::Japanese),
+            "en" => Some(Language::English),
+            "zh" => Some(Language::Chinese),
+            "ko" => Some(Language::Korean),
             _ => None,
         }
     }
-    
+
+    /// Negotiate a supported language from an HTTP `Accept-Language` header: parse its
+    /// comma-separated `tag[;q=weight]` entries (missing `q` defaults to `1.0`), sort by
+    /// descending quality, and return the first tag `from_code` recognizes.
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut weighted: Vec<(f32, &str)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let entry = entry.trim();
+                if entry.is_empty() {
+                    return None;
+                }
+                let mut segments = entry.split(';');
+                let tag = segments.next()?.trim();
+                let quality = segments
+                    .find_map(|seg| seg.trim().strip_prefix("q="))
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                Some((quality, tag))
+            })
+            .collect();
+
+        weighted.sort_by(|a, b| b.0.total_cmp(&a.0));
+        weighted.into_iter().find_map(|(_, tag)| Self::from_code(tag))
+    }
+
     pub fn code(&self) -> &'static str {
         match self {
             Language::Japanese => "ja",
@@ -46,50 +86,266 @@ impl Language {
             Language::Korean => "한국어",
         }
     }
+
+    /// Guess a text's language from character-script statistics: count Hiragana/
+    /// Katakana, Han, Hangul, and Latin-alphabetic code points and pick by presence,
+    /// kana first since a Han+kana mix is Japanese rather than Chinese. Returns the
+    /// guessed language with a confidence score (the share of all classified characters
+    /// in the winning language's scripts, kana plus Han for Japanese), or `None` if
+    /// `text` has no alphabetic/script content to
+    /// classify (e.g. empty, or only digits/punctuation).
+    pub fn detect(text: &str) -> Option<(Self, f32)> {
+        let mut kana = 0u32;
+        let mut han = 0u32;
+        let mut hangul = 0u32;
+        let mut latin = 0u32;
+
+        for c in text.chars() {
+            match c as u32 {
+                0x3040..=0x30FF => kana += 1,
+                0x4E00..=0x9FFF | 0x3400..=0x4DBF => han += 1,
+                0xAC00..=0xD7A3 | 0x1100..=0x11FF => hangul += 1,
+                _ if c.is_ascii_alphabetic() => latin += 1,
+                _ => {}
+            }
+        }
+
+        let total = (kana + han + hangul + latin) as f32;
+        if total == 0.0 {
+            return None;
+        }
+
+        // Kana presence wins the tie-break for Japanese even when Han code points
+        // outnumber it (most Japanese prose is kanji-heavy but always kana-threaded).
+        if kana > 0 {
+            Some((Language::Japanese, (kana + han) as f32 / total))
+        } else if hangul > 0 {
+            Some((Language::Korean, hangul as f32 / total))
+        } else if han > 0 {
+            Some((Language::Chinese, han as f32 / total))
+        } else {
+            Some((Language::English, latin as f32 / total))
+        }
+    }
 }
 
 /// i18n manager
 pub struct I18n {
     language: Language,
     translations: HashMap<String, String>,
+    /// Japanese translation table, consulted when `language` is missing a key. Japanese
+    /// is the crate's original/most complete language, so it's the natural fallback
+    /// rather than echoing the raw key.
+    fallback: HashMap<String, String>,
 }
 
 impl I18n {
     pub fn new(lang_code: &str) -> Self {
         let language = Language::from_code(lang_code).unwrap_or(Language::Japanese);
         let translations = load_translations(&language);
-        
+        let fallback = load_translations(&Language::Japanese);
+
         Self {
             language,
             translations,
+            fallback,
         }
     }
-    
-    /// Get translation
+
+    /// Load per-language resource files from `dir` (one file per language, e.g.
+    /// `ja.ftl`/`en.ftl`, simple `key = value` lines with `#` comments) instead of the
+    /// built-in hard-coded tables, so translators can ship bundles without recompiling.
+    /// A language whose file is missing from `dir` falls back to its built-in table.
+    pub fn from_dir(dir: impl AsRef<Path>, lang_code: &str) -> Self {
+        let dir = dir.as_ref();
+        let language = Language::from_code(lang_code).unwrap_or(Language::Japanese);
+
+        let translations = load_resource_file(dir, language).unwrap_or_else(|| load_translations(&language));
+        let fallback = load_resource_file(dir, Language::Japanese)
+            .unwrap_or_else(|| load_translations(&Language::Japanese));
+
+        Self {
+            language,
+            translations,
+            fallback,
+        }
+    }
+
+    /// Auto-select a UI language from sample `text` via [`Language::detect`], falling
+    /// back to Japanese when no script-based guess is possible (e.g. `text` is empty).
+    /// For callers that haven't asked the user for a language preference yet.
+    pub fn from_detected_language(text: &str) -> Self {
+        let language = Language::detect(text).map(|(lang, _)| lang).unwrap_or(Language::Japanese);
+        Self::new(language.code())
+    }
+
+    /// Get translation, falling back to the Japanese table and then the raw key itself
+    /// when `language` doesn't define `key`.
     pub fn t(&self, key: &str) -> &str {
         self.translations
             .get(key)
+            .or_else(|| self.fallback.get(key))
             .map(|s| s.as_str())
             .unwrap_or(key)
     }
-    
+
     /// Get current language
     pub fn language(&self) -> Language {
         self.language
     }
     
-    /// Format with arguments
+    /// Format with positional arguments (`{0}`, `{1}`, ...).
     pub fn tf(&self, key: &str, args: &[&str]) -> String {
         let template = self.t(key);
         let mut result = template.to_string();
-        
+
         for (i, arg) in args.iter().enumerate() {
             let placeholder = format!("{{{}}}", i);
             result = result.replace(&placeholder, arg);
         }
-        
+
         result
     }
+
+    /// Format with named arguments, supporting plain `{name}` substitution as well as
+    /// ICU MessageFormat-style `{name, plural, one {...} other {...}}` and
+    /// `{name, select, male {...} female {...} other {...}}` blocks. `#` inside a
+    /// `plural` arm is replaced with the (stringified) count. An arm missing for the
+    /// resolved category falls back to `other`; a named argument missing from `args`
+    /// leaves its `{name}` placeholder untouched rather than panicking.
+    pub fn tf_named(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let template = self.t(key);
+        let arg_map: HashMap<&str, &str> = args.iter().copied().collect();
+        self.format_template(template, &arg_map)
+    }
+
+    fn format_template(&self, template: &str, args: &HashMap<&str, &str>) -> String {
+        let chars: Vec<char> = template.chars().collect();
+        let mut out = String::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            if chars[i] == '{' {
+                if let Some(close) = find_matching_brace(&chars, i) {
+                    let inner: String = chars[i + 1..close].iter().collect();
+                    out.push_str(&self.resolve_placeholder(&inner, args));
+                    i = close + 1;
+                    continue;
+                }
+            }
+            out.push(chars[i]);
+            i += 1;
+        }
+
+        out
+    }
+
+    /// Resolve the contents of a single `{...}` block (without the outer braces).
+    fn resolve_placeholder(&self, inner: &str, args: &HashMap<&str, &str>) -> String {
+        let mut parts = inner.splitn(3, ',');
+        let name = parts.next().unwrap_or("").trim();
+        let kind = parts.next().map(str::trim);
+        let arms_str = parts.next().unwrap_or("");
+
+        match kind {
+            None => args
+                .get(name)
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| format!("{{{}}}", inner)),
+            Some("plural") => {
+                let count: i64 = args.get(name).and_then(|s| s.parse().ok()).unwrap_or(0);
+                let category = plural_category(self.language, count);
+                let body = select_arm(arms_str, category).replace('#', &count.to_string());
+                self.format_template(&body, args)
+            }
+            Some("select") => {
+                let selector = args.get(name).copied().unwrap_or("other");
+                let body = select_arm(arms_str, selector);
+                self.format_template(&body, args)
+            }
+            Some(_) => format!("{{{}}}", inner),
+        }
+    }
+}
+
+/// CLDR plural category for `count` under `language`'s pluralization rules. English
+/// distinguishes singular (`one`) from everything else; Japanese, Chinese, and Korean
+/// have no grammatical plural, so they always select `other`.
+fn plural_category(language: Language, count: i64) -> &'static str {
+    match language {
+        Language::English => {
+            if count == 1 {
+                "one"
+            } else {
+                "other"
+            }
+        }
+        Language::Japanese | Language::Chinese | Language::Korean => "other",
+    }
+}
+
+/// Given the char index `open` where `chars[open]` is `{`, return the char index of its
+/// matching `}` (brace-depth aware, since `plural`/`select` arms nest one level of
+/// braces inside the outer placeholder).
+fn find_matching_brace(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, &c) in chars.iter().enumerate().skip(open) {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse `arm_name {arm body} arm_name {arm body} ...` into `(name, body)` pairs.
+fn parse_arms(s: &str) -> Vec<(String, String)> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut arms = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        while i < chars.len() && chars[i].is_whitespace() {
+            i += 1;
+        }
+        let name_start = i;
+        while i < chars.len() && chars[i] != '{' && !chars[i].is_whitespace() {
+            i += 1;
+        }
+        if i == name_start {
+            break;
+        }
+        let name: String = chars[name_start..i].iter().collect();
+
+        while i < chars.len() && chars[i].is_whitespace() {
+            i += 1;
+        }
+        let Some(close) = (i < chars.len() && chars[i] == '{').then(|| find_matching_brace(&chars, i)).flatten() else {
+            break;
+        };
+        let body: String = chars[i + 1..close].iter().collect();
+        arms.push((name, body));
+        i = close + 1;
+    }
+
+    arms
+}
+
+/// Pick the arm matching `category`, falling back to the `other` arm (required by the
+/// ICU spec as a catch-all) when the resolved category has no explicit arm.
+fn select_arm(arms_str: &str, category: &str) -> String {
+    let arms = parse_arms(arms_str);
+    arms.iter()
+        .find(|(name, _)| name == category)
+        .or_else(|| arms.iter().find(|(name, _)| name == "other"))
+        .map(|(_, body)| body.clone())
+        .unwrap_or_default()
 }
 
 impl Default for I18n {
@@ -106,7 +362,10 @@ fn load_translations(lang: &Language) -> HashMap<String, String> {
         Language::Japanese => {
             map.insert("welcome".into(), "ようこそ".into());
             map.insert("project_created".into(), "プロジェクトを作成しました".into());
-            map.insert("scene_generated".into(), "シーンを生成しました".into());
+            map.insert(
+                "scene_generated".into(),
+                "{count, plural, other {シーンを#件生成しました}}".into(),
+            );
             map.insert("error".into(), "エラー".into());
             map.insert("loading".into(), "読み込み中...".into());
             map.insert("save".into(), "保存".into());
@@ -129,7 +388,10 @@ fn load_translations(lang: &Language) -> HashMap<String, String> {
         Language::English => {
             map.insert("welcome".into(), "Welcome".into());
             map.insert("project_created".into(), "Project created".into());
-            map.insert("scene_generated".into(), "Scene generated".into());
+            map.insert(
+                "scene_generated".into(),
+                "{count, plural, one {# scene generated} other {# scenes generated}}".into(),
+            );
             map.insert("error".into(), "Error".into());
             map.insert("loading".into(), "Loading...".into());
             map.insert("save".into(), "Save".into());
@@ -152,7 +414,10 @@ fn load_translations(lang: &Language) -> HashMap<String, String> {
         Language::Chinese => {
             map.insert("welcome".into(), "欢迎".into());
             map.insert("project_created".into(), "项目已创建".into());
-            map.insert("scene_generated".into(), "场景已生成".into());
+            map.insert(
+                "scene_generated".into(),
+                "{count, plural, other {已生成 # 个场景}}".into(),
+            );
             map.insert("error".into(), "错误".into());
             map.insert("loading".into(), "加载中...".into());
             map.insert("save".into(), "保存".into());
@@ -175,7 +440,10 @@ fn load_translations(lang: &Language) -> HashMap<String, String> {
         Language::Korean => {
             map.insert("welcome".into(), "환영합니다".into());
             map.insert("project_created".into(), "프로젝트가 생성되었습니다".into());
-            map.insert("scene_generated".into(), "장면이 생성되었습니다".into());
+            map.insert(
+                "scene_generated".into(),
+                "{count, plural, other {장면 #개가 생성되었습니다}}".into(),
+            );
             map.insert("error".into(), "오류".into());
             map.insert("loading".into(), "로딩 중...".into());
             map.insert("save".into(), "저장".into());
@@ -200,12 +468,57 @@ fn load_translations(lang: &Language) -> HashMap<String, String> {
     map
 }
 
-/// Initialize i18n system
+/// Typed, compile-time-checked translation accessors generated by `build.rs` from
+/// `i18n/locales/*.ftl` — one `pub fn` per key, named after the key, taking an `&I18n`
+/// plus one `&str` argument per `{placeholder}` the key's template actually uses (so
+/// `keys::welcome(&i18n)` / `keys::scene_generated(&i18n, "3")` replace the untyped
+/// `i18n.t("welcome")` / `i18n.tf_named("scene_generated", &[("count", "3")])` call
+/// sites). `build.rs` fails the build if any language file is missing a key another one
+/// defines, turning a missing translation into a build failure instead of a runtime
+/// fallback. Falls back to the runtime `t`/`tf`/`tf_named` lookups for dynamic keys that
+/// aren't known until runtime.
+pub mod keys {
+    include!(concat!(env!("OUT_DIR"), "/i18n_keys.rs"));
+}
+
+/// Resource directory registered by `init()`, read by callers that want `I18n::from_dir`
+/// to point at the same root the rest of the process was configured with.
+static RESOURCE_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+/// Default resource directory, relative to the process's working directory.
+const DEFAULT_RESOURCE_ROOT: &str = "i18n/locales";
+
+/// Read `dir/{lang.code()}.ftl` as a flat `key = value` resource file (one pair per
+/// line, `#`-prefixed lines and blank lines ignored). Returns `None` if the file
+/// doesn't exist or can't be read, so callers can fall back to the built-in table.
+fn load_resource_file(dir: &Path, lang: Language) -> Option<HashMap<String, String>> {
+    let path = dir.join(format!("{}.ftl", lang.code()));
+    let content = std::fs::read_to_string(path).ok()?;
+
+    let mut map = HashMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            map.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    Some(map)
+}
+
+/// Initialize i18n system, registering the default resource root for `I18n::from_dir`.
 pub fn init() {
-    // Future: Load from external files
+    let _ = RESOURCE_ROOT.set(PathBuf::from(DEFAULT_RESOURCE_ROOT));
     tracing::info!("i18n system initialized");
 }
 
+/// The resource root registered by `init()`, if it has run.
+pub fn resource_root() -> Option<&'static Path> {
+    RESOURCE_ROOT.get().map(PathBuf::as_path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,6 +532,25 @@ mod tests {
         assert_eq!(Language::from_code("unknown"), None);
     }
     
+    #[test]
+    fn test_from_code_bcp47_script_and_region_variants() {
+        assert_eq!(Language::from_code("zh-Hans-CN"), Some(Language::Chinese));
+        assert_eq!(Language::from_code("en_US"), Some(Language::English));
+        assert_eq!(Language::from_code("JA-jp"), Some(Language::Japanese));
+    }
+
+    #[test]
+    fn test_from_accept_language_picks_highest_quality_supported() {
+        let header = "fr-FR;q=0.9, en;q=0.8, zh-CN;q=0.95";
+        assert_eq!(Language::from_accept_language(header), Some(Language::Chinese));
+    }
+
+    #[test]
+    fn test_from_accept_language_skips_unsupported_tags() {
+        let header = "fr-FR, de-DE;q=0.9, ko;q=0.5";
+        assert_eq!(Language::from_accept_language(header), Some(Language::Korean));
+    }
+
     #[test]
     fn test_i18n_translation() {
         let ja = I18n::new("ja");
@@ -235,4 +567,151 @@ mod tests {
         let result = ja.tf("welcome", &["test"]);
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn test_key_missing_from_every_table_returns_key_itself() {
+        let en = I18n::new("en");
+        assert_eq!(en.t("not_a_real_key"), "not_a_real_key");
+        // "welcome" exists in both, so this doesn't exercise the fallback path on its
+        // own, but confirms the active table still wins over the fallback table.
+        assert_eq!(en.t("welcome"), "Welcome");
+    }
+
+    #[test]
+    fn test_from_dir_loads_resource_files_and_falls_back_for_missing_keys() {
+        let dir = std::env::temp_dir().join(format!("novelist_i18n_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("en.ftl"), "# comment\nwelcome = Hi there\n").unwrap();
+        std::fs::write(dir.join("ja.ftl"), "welcome = やあ\nerror = エラー\n").unwrap();
+
+        let i18n = I18n::from_dir(&dir, "en");
+        assert_eq!(i18n.t("welcome"), "Hi there");
+        // "error" isn't in the English file, so it should fall back to the Japanese one.
+        assert_eq!(i18n.t("error"), "エラー");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_tf_named_plural_english_distinguishes_one_and_other() {
+        let en = I18n::new("en");
+        let template = "{count, plural, one {# scene} other {# scenes}}";
+
+        let singular = en.format_template(template, &[("count", "1")].into_iter().collect());
+        assert_eq!(singular, "1 scene");
+
+        let plural = en.format_template(template, &[("count", "3")].into_iter().collect());
+        assert_eq!(plural, "3 scenes");
+    }
+
+    #[test]
+    fn test_scene_generated_key_uses_plural_form_in_every_language() {
+        // `scene_generated` is the one built-in key with a real `{count, plural, ...}`
+        // template (mirroring i18n/locales/*.ftl), exercising the same placeholder-arity
+        // path `build.rs` uses to decide `keys::scene_generated` takes a `count` arg.
+        let en = I18n::new("en");
+        assert_eq!(en.tf_named("scene_generated", &[("count", "1")]), "1 scene generated");
+        assert_eq!(en.tf_named("scene_generated", &[("count", "3")]), "3 scenes generated");
+
+        let ja = I18n::new("ja");
+        assert_eq!(ja.tf_named("scene_generated", &[("count", "2")]), "シーンを2件生成しました");
+    }
+
+    #[test]
+    fn test_tf_named_plural_japanese_always_other() {
+        let ja = I18n::new("ja");
+        let result = ja.format_template(
+            "{count, plural, one {# 個} other {# 件}}",
+            &[("count", "1")].into_iter().collect(),
+        );
+        assert_eq!(result, "1 件");
+    }
+
+    #[test]
+    fn test_tf_named_select_gender() {
+        let en = I18n::new("en");
+        let result = en.format_template(
+            "{gender, select, male {He} female {She} other {They}}",
+            &[("gender", "female")].into_iter().collect(),
+        );
+        assert_eq!(result, "She");
+
+        let fallback = en.format_template(
+            "{gender, select, male {He} female {She} other {They}}",
+            &[("gender", "unknown")].into_iter().collect(),
+        );
+        assert_eq!(fallback, "They");
+    }
+
+    #[test]
+    fn test_tf_named_missing_arg_leaves_placeholder() {
+        let en = I18n::new("en");
+        let result = en.format_template("Hello {name}", &HashMap::new());
+        assert_eq!(result, "Hello {name}");
+    }
+
+    #[test]
+    fn test_detect_identifies_scripts() {
+        let (lang, confidence) = Language::detect("魔法の世界について学ぶ物語").unwrap();
+        assert_eq!(lang, Language::Japanese);
+        assert!(confidence > 0.5);
+
+        let (lang, _) = Language::detect("这是一个关于魔法的故事").unwrap();
+        assert_eq!(lang, Language::Chinese);
+
+        let (lang, _) = Language::detect("이것은 마법에 관한 이야기입니다").unwrap();
+        assert_eq!(lang, Language::Korean);
+
+        let (lang, confidence) = Language::detect("This is a story about magic").unwrap();
+        assert_eq!(lang, Language::English);
+        assert!(confidence > 0.5);
+    }
+
+    #[test]
+    fn test_detect_returns_none_for_no_script_content() {
+        assert_eq!(Language::detect("123 !? --- 456"), None);
+    }
+
+    #[test]
+    fn test_detect_prefers_japanese_on_kana_tiebreak() {
+        // Han code points alone would look Chinese, but any kana at all means Japanese.
+        let (lang, _) = Language::detect("魔法の力").unwrap();
+        assert_eq!(lang, Language::Japanese);
+    }
+
+    #[test]
+    fn test_from_detected_language_picks_matching_table() {
+        let i18n = I18n::from_detected_language("This is worldbuilding prose in English");
+        assert_eq!(i18n.language(), Language::English);
+        assert_eq!(i18n.t("welcome"), "Welcome");
+
+        let empty = I18n::from_detected_language("");
+        assert_eq!(empty.language(), Language::Japanese);
+    }
+
+    #[test]
+    fn test_builtin_tables_define_the_same_key_set_in_every_language() {
+        let tables = [
+            load_translations(&Language::Japanese),
+            load_translations(&Language::English),
+            load_translations(&Language::Chinese),
+            load_translations(&Language::Korean),
+        ];
+        let reference: std::collections::BTreeSet<_> = tables[0].keys().collect();
+        for table in &tables[1..] {
+            let keys: std::collections::BTreeSet<_> = table.keys().collect();
+            assert_eq!(
+                keys, reference,
+                "every Language's built-in table must define the same keys (this is what \
+                 build.rs also checks for i18n/locales/*.ftl before generating i18n::keys)"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_dir_missing_file_falls_back_to_builtin_table() {
+        let dir = std::env::temp_dir().join(format!("novelist_i18n_test_empty_{}", std::process::id()));
+        let i18n = I18n::from_dir(&dir, "en");
+        assert_eq!(i18n.t("welcome"), "Welcome");
+    }
 }
@@ -9,11 +9,13 @@
 pub mod ffi;
 pub mod i18n;
 pub mod models;
+pub mod normalize;
 pub mod rag;
 pub mod tokenizer;
 
 pub use i18n::I18n;
 pub use models::*;
+pub use normalize::Normalizer;
 pub use rag::{Document, Retriever, SearchResult};
 pub use tokenizer::{Token, Tokenizer};
 
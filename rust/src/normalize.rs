@@ -0,0 +1,304 @@
+//! Text normalization pipeline
+//!
+//! Full-width vs half-width forms, combining diacritics, and kana/romaji spellings of
+//! the same word all produce divergent embeddings and token streams unless collapsed
+//! to a canonical form first. `Normalizer` is a small composable, builder-style
+//! pipeline applied consistently before both tokenization and embedding.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Composable text-normalization pipeline.
+///
+/// Built with chained `with_*` toggles (mirroring the crate's other builder-style
+/// configs), then applied via [`Normalizer::normalize`].
+#[derive(Debug, Clone)]
+pub struct Normalizer {
+    nfkc: bool,
+    lowercase: bool,
+    strip_diacritics: bool,
+    kana_romaji_fold: bool,
+    transliterate: bool,
+}
+
+impl Normalizer {
+    /// NFKC + lowercase on, diacritic stripping, kana/romaji folding, and
+    /// transliteration off.
+    pub fn new() -> Self {
+        Self {
+            nfkc: true,
+            lowercase: true,
+            strip_diacritics: false,
+            kana_romaji_fold: false,
+            transliterate: false,
+        }
+    }
+
+    pub fn with_nfkc(mut self, enabled: bool) -> Self {
+        self.nfkc = enabled;
+        self
+    }
+
+    pub fn with_lowercase(mut self, enabled: bool) -> Self {
+        self.lowercase = enabled;
+        self
+    }
+
+    pub fn with_strip_diacritics(mut self, enabled: bool) -> Self {
+        self.strip_diacritics = enabled;
+        self
+    }
+
+    pub fn with_kana_romaji_fold(mut self, enabled: bool) -> Self {
+        self.kana_romaji_fold = enabled;
+        self
+    }
+
+    /// For non-CJK text, approximate unmapped non-ASCII glyphs (Cyrillic, Greek, or
+    /// anything else that isn't Latin/CJK) with a plain-ASCII transliteration, so e.g.
+    /// "Москва" and "Moskva" can collapse to the same key. Applied after diacritic
+    /// stripping so accented Latin already has a chance to fold on its own; any glyph
+    /// this can't map to ASCII (via the built-in script tables or its NFD base letter)
+    /// is dropped rather than left as a mismatch-prone foreign code point.
+    pub fn with_transliterate(mut self, enabled: bool) -> Self {
+        self.transliterate = enabled;
+        self
+    }
+
+    /// Apply the pipeline, producing a canonical key for downstream tokenization/embedding.
+    pub fn normalize(&self, text: &str) -> String {
+        let mut out = if self.nfkc {
+            text.nfkc().collect::<String>()
+        } else {
+            text.to_string()
+        };
+
+        if contains_cjk(&out) {
+            // CJK text: never transliterate or strip diacritics, so kanji/kana stay intact.
+            if self.kana_romaji_fold {
+                out = fold_kana_to_romaji(&out);
+            }
+            if self.lowercase {
+                out = out.to_lowercase();
+            }
+            return out;
+        }
+
+        if self.strip_diacritics {
+            out = strip_diacritics(&out);
+        }
+        if self.transliterate {
+            out = transliterate_to_ascii(&out);
+        }
+        if self.lowercase {
+            out = out.to_lowercase();
+        }
+
+        out
+    }
+}
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn contains_cjk(text: &str) -> bool {
+    text.chars().any(|c| {
+        matches!(c as u32,
+            0x3040..=0x309F | // Hiragana
+            0x30A0..=0x30FF | // Katakana
+            0x4E00..=0x9FFF | // Kanji
+            0x3400..=0x4DBF   // CJK Extension A
+        )
+    })
+}
+
+/// Strip combining diacritical marks (e.g. "café" -> "cafe") via NFD decomposition
+/// followed by filtering the combining-mark block out of the decomposed stream.
+fn strip_diacritics(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !matches!(*c as u32, 0x0300..=0x036F))
+        .collect()
+}
+
+/// Common Cyrillic letters (lowercase) -> ASCII approximation, GOST/Hepburn-ish.
+const CYRILLIC_ASCII: &[(char, &str)] = &[
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"), ('д', "d"),
+    ('е', "e"), ('ё', "e"), ('ж', "zh"), ('з', "z"), ('и', "i"),
+    ('й', "i"), ('к', "k"), ('л', "l"), ('м', "m"), ('н', "n"),
+    ('о', "o"), ('п', "p"), ('р', "r"), ('с', "s"), ('т', "t"),
+    ('у', "u"), ('ф', "f"), ('х', "kh"), ('ц', "ts"), ('ч', "ch"),
+    ('ш', "sh"), ('щ', "shch"), ('ъ', ""), ('ы', "y"), ('ь', ""),
+    ('э', "e"), ('ю', "yu"), ('я', "ya"),
+];
+
+/// Common (lowercase) Greek letters -> ASCII approximation.
+const GREEK_ASCII: &[(char, &str)] = &[
+    ('α', "a"), ('β', "b"), ('γ', "g"), ('δ', "d"), ('ε', "e"),
+    ('ζ', "z"), ('η', "i"), ('θ', "th"), ('ι', "i"), ('κ', "k"),
+    ('λ', "l"), ('μ', "m"), ('ν', "n"), ('ξ', "x"), ('ο', "o"),
+    ('π', "p"), ('ρ', "r"), ('σ', "s"), ('ς', "s"), ('τ', "t"),
+    ('υ', "y"), ('φ', "f"), ('χ', "ch"), ('ψ', "ps"), ('ω', "o"),
+];
+
+/// Approximate every non-ASCII character with plain ASCII: known-script tables first
+/// (Cyrillic, Greek), then the character's NFD base letter if that happens to be ASCII
+/// (covers accented Latin that `strip_diacritics` didn't already handle), and otherwise
+/// drop the glyph — the honest "unknown approximation" for a script with no mapping.
+fn transliterate_to_ascii(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii() {
+                return c.to_string();
+            }
+
+            let lower = c.to_lowercase().next().unwrap_or(c);
+            if let Some((_, ascii)) = CYRILLIC_ASCII.iter().find(|(k, _)| *k == lower) {
+                return ascii.to_string();
+            }
+            if let Some((_, ascii)) = GREEK_ASCII.iter().find(|(k, _)| *k == lower) {
+                return ascii.to_string();
+            }
+
+            c.nfd().filter(char::is_ascii).collect()
+        })
+        .collect()
+}
+
+/// Basic gojuon + common digraphs, Hepburn-ish romanization. Covers enough of the
+/// syllabary to fold common loanwords ("ラーメン"/"らーめん" -> "ra-men"); a long vowel
+/// mark folds to a hyphen rather than being dropped, since dropping it would collide
+/// unrelated words.
+const KANA_ROMAJI: &[(&str, &str)] = &[
+    ("きゃ", "kya"), ("きゅ", "kyu"), ("きょ", "kyo"),
+    ("しゃ", "sha"), ("しゅ", "shu"), ("しょ", "sho"),
+    ("ちゃ", "cha"), ("ちゅ", "chu"), ("ちょ", "cho"),
+    ("にゃ", "nya"), ("にゅ", "nyu"), ("にょ", "nyo"),
+    ("ひゃ", "hya"), ("ひゅ", "hyu"), ("ひょ", "hyo"),
+    ("みゃ", "mya"), ("みゅ", "myu"), ("みょ", "myo"),
+    ("りゃ", "rya"), ("りゅ", "ryu"), ("りょ", "ryo"),
+    ("ぎゃ", "gya"), ("ぎゅ", "gyu"), ("ぎょ", "gyo"),
+    ("じゃ", "ja"), ("じゅ", "ju"), ("じょ", "jo"),
+    ("びゃ", "bya"), ("びゅ", "byu"), ("びょ", "byo"),
+    ("ぴゃ", "pya"), ("ぴゅ", "pyu"), ("ぴょ", "pyo"),
+    ("あ", "a"), ("い", "i"), ("う", "u"), ("え", "e"), ("お", "o"),
+    ("か", "ka"), ("き", "ki"), ("く", "ku"), ("け", "ke"), ("こ", "ko"),
+    ("が", "ga"), ("ぎ", "gi"), ("ぐ", "gu"), ("げ", "ge"), ("ご", "go"),
+    ("さ", "sa"), ("し", "shi"), ("す", "su"), ("せ", "se"), ("そ", "so"),
+    ("ざ", "za"), ("じ", "ji"), ("ず", "zu"), ("ぜ", "ze"), ("ぞ", "zo"),
+    ("た", "ta"), ("ち", "chi"), ("つ", "tsu"), ("て", "te"), ("と", "to"),
+    ("だ", "da"), ("ぢ", "ji"), ("づ", "zu"), ("で", "de"), ("ど", "do"),
+    ("な", "na"), ("に", "ni"), ("ぬ", "nu"), ("ね", "ne"), ("の", "no"),
+    ("は", "ha"), ("ひ", "hi"), ("ふ", "fu"), ("へ", "he"), ("ほ", "ho"),
+    ("ば", "ba"), ("び", "bi"), ("ぶ", "bu"), ("べ", "be"), ("ぼ", "bo"),
+    ("ぱ", "pa"), ("ぴ", "pi"), ("ぷ", "pu"), ("ぺ", "pe"), ("ぽ", "po"),
+    ("ま", "ma"), ("み", "mi"), ("む", "mu"), ("め", "me"), ("も", "mo"),
+    ("や", "ya"), ("ゆ", "yu"), ("よ", "yo"),
+    ("ら", "ra"), ("り", "ri"), ("る", "ru"), ("れ", "re"), ("ろ", "ro"),
+    ("わ", "wa"), ("を", "wo"), ("ん", "n"),
+    ("っ", ""), // sokuon: geminates the following consonant; approximated as silent here
+    ("ー", "-"),
+];
+
+/// Fold hiragana and katakana runs to a lowercase romaji key, leaving non-kana
+/// characters untouched. Katakana is folded by first mapping it to its hiragana
+/// equivalent (offset by a fixed codepoint distance), then through the same table.
+fn fold_kana_to_romaji(text: &str) -> String {
+    let hiragana: String = text
+        .chars()
+        .map(|c| {
+            if ('\u{30A1}'..='\u{30F6}').contains(&c) {
+                char::from_u32(c as u32 - 0x60).unwrap_or(c)
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    let mut out = String::new();
+    let chars: Vec<char> = hiragana.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let mut matched = false;
+        if i + 1 < chars.len() {
+            let two: String = chars[i..i + 2].iter().collect();
+            if let Some((_, romaji)) = KANA_ROMAJI.iter().find(|(k, _)| *k == two) {
+                out.push_str(romaji);
+                i += 2;
+                matched = true;
+            }
+        }
+        if !matched {
+            let one: String = chars[i..i + 1].iter().collect();
+            if let Some((_, romaji)) = KANA_ROMAJI.iter().find(|(k, _)| *k == one) {
+                out.push_str(romaji);
+            } else {
+                out.push(chars[i]);
+            }
+            i += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nfkc_folds_fullwidth_ascii() {
+        let normalizer = Normalizer::new();
+        // Fullwidth "ABC" -> halfwidth, then lowercased.
+        assert_eq!(normalizer.normalize("ＡＢＣ"), "abc");
+    }
+
+    #[test]
+    fn test_strip_diacritics() {
+        let normalizer = Normalizer::new().with_strip_diacritics(true);
+        assert_eq!(normalizer.normalize("café"), "cafe");
+    }
+
+    #[test]
+    fn test_cjk_text_not_transliterated() {
+        let normalizer = Normalizer::new().with_strip_diacritics(true);
+        assert_eq!(normalizer.normalize("魔法"), "魔法");
+    }
+
+    #[test]
+    fn test_transliterate_cyrillic_to_ascii() {
+        let normalizer = Normalizer::new().with_transliterate(true);
+        assert_eq!(normalizer.normalize("Москва"), "moskva");
+    }
+
+    #[test]
+    fn test_transliterate_greek_to_ascii() {
+        let normalizer = Normalizer::new().with_transliterate(true);
+        assert_eq!(normalizer.normalize("λογος"), "logos");
+    }
+
+    #[test]
+    fn test_transliterate_drops_unmapped_glyphs() {
+        let normalizer = Normalizer::new().with_transliterate(true);
+        // No Cyrillic/Greek/Latin mapping and no ASCII NFD base -> dropped entirely.
+        assert_eq!(normalizer.normalize("✓"), "");
+    }
+
+    #[test]
+    fn test_transliterate_off_by_default_leaves_foreign_scripts_untouched() {
+        let normalizer = Normalizer::new();
+        assert_eq!(normalizer.normalize("Москва"), "москва");
+    }
+
+    #[test]
+    fn test_cjk_text_skips_transliteration() {
+        let normalizer = Normalizer::new().with_transliterate(true);
+        assert_eq!(normalizer.normalize("魔法"), "魔法");
+    }
+
+    #[test]
+    fn test_kana_romaji_folding_collapses_variants() {
+        let normalizer = Normalizer::new().with_kana_romaji_fold(true);
+        assert_eq!(normalizer.normalize("ラーメン"), normalizer.normalize("らーめん"));
+    }
+}
@@ -0,0 +1,164 @@
+//! BM25 lexical scoring
+//!
+//! Dense cosine similarity over `SimpleEmbedder` vectors is weak for exact keyword hits
+//! (character names, place names). This module builds an inverted index over tokenized
+//! documents and scores queries with Okapi BM25, so `Retriever` can fuse lexical and
+//! dense rankings (see [`crate::rag::SearchMode::Hybrid`]).
+
+use std::collections::HashMap;
+
+use crate::tokenizer::{MultiLanguageTokenizer, TokenType};
+
+const DEFAULT_K1: f32 = 1.2;
+const DEFAULT_B: f32 = 0.75;
+
+/// Inverted index plus document-length statistics needed for BM25 scoring.
+pub struct Bm25Index {
+    /// term -> (doc_idx, term frequency in that doc)
+    postings: HashMap<String, Vec<(usize, u32)>>,
+    doc_freq: HashMap<String, u32>,
+    doc_lens: Vec<usize>,
+    avgdl: f32,
+    n_docs: usize,
+    k1: f32,
+    b: f32,
+}
+
+impl Bm25Index {
+    /// Build an index over `documents`' content, tokenized with `tokenizer`. Uses
+    /// [`MultiLanguageTokenizer::tokenize_canonical`] so width-folding, lowercasing, and
+    /// CJK segmentation are applied before terms land in the inverted index.
+    pub fn build(documents: &[&str], tokenizer: &MultiLanguageTokenizer) -> Self {
+        let mut postings: HashMap<String, Vec<(usize, u32)>> = HashMap::new();
+        let mut doc_freq: HashMap<String, u32> = HashMap::new();
+        let mut doc_lens = Vec::with_capacity(documents.len());
+
+        for (doc_idx, content) in documents.iter().enumerate() {
+            let tokens = tokenizer.tokenize_canonical(content);
+            let mut term_counts: HashMap<String, u32> = HashMap::new();
+            for token in tokens {
+                if token.text.trim().is_empty() || token.token_type == TokenType::Punctuation {
+                    continue;
+                }
+                *term_counts.entry(token.text).or_insert(0) += 1;
+            }
+
+            doc_lens.push(term_counts.values().sum::<u32>() as usize);
+
+            for (term, tf) in term_counts {
+                postings.entry(term.clone()).or_default().push((doc_idx, tf));
+                *doc_freq.entry(term).or_insert(0) += 1;
+            }
+        }
+
+        let n_docs = documents.len();
+        let avgdl = if n_docs > 0 {
+            doc_lens.iter().sum::<usize>() as f32 / n_docs as f32
+        } else {
+            0.0
+        };
+
+        Self {
+            postings,
+            doc_freq,
+            doc_lens,
+            avgdl,
+            n_docs,
+            k1: DEFAULT_K1,
+            b: DEFAULT_B,
+        }
+    }
+
+    /// Every distinct term in the index, for fuzzy expansion over the vocabulary.
+    pub fn vocabulary(&self) -> impl Iterator<Item = &str> {
+        self.postings.keys().map(|s| s.as_str())
+    }
+
+    fn idf(&self, term: &str) -> f32 {
+        let n = self.n_docs as f32;
+        let df = self.doc_freq.get(term).copied().unwrap_or(0) as f32;
+        ((n - df + 0.5) / (df + 0.5) + 1.0).ln()
+    }
+
+    /// Score every document containing at least one query term, returning
+    /// `(doc_idx, bm25_score)` pairs sorted by nothing in particular (caller sorts).
+    pub fn search(&self, query: &str, tokenizer: &MultiLanguageTokenizer) -> Vec<(usize, f32)> {
+        if self.n_docs == 0 {
+            return Vec::new();
+        }
+
+        let query_tokens = tokenizer.tokenize_canonical(query);
+        let mut scores: HashMap<usize, f32> = HashMap::new();
+
+        for token in query_tokens {
+            if token.text.trim().is_empty() || token.token_type == TokenType::Punctuation {
+                continue;
+            }
+            let Some(postings) = self.postings.get(&token.text) else {
+                continue;
+            };
+            let idf = self.idf(&token.text);
+
+            for &(doc_idx, tf) in postings {
+                let tf = tf as f32;
+                let dl = self.doc_lens[doc_idx] as f32;
+                let denom = tf + self.k1 * (1.0 - self.b + self.b * dl / self.avgdl.max(1.0));
+                let score = idf * (tf * (self.k1 + 1.0)) / denom;
+                *scores.entry(doc_idx).or_insert(0.0) += score;
+            }
+        }
+
+        scores.into_iter().collect()
+    }
+}
+
+/// Fuse multiple ranked lists with Reciprocal Rank Fusion: `score(d) = sum 1/(k + rank_d)`
+/// over the lists `d` appears in, where `rank_d` is 1-indexed. `k` defaults to 60 in
+/// practice, matching the constant used in the original RRF paper.
+pub fn reciprocal_rank_fusion(ranked_lists: &[Vec<usize>], k: f32) -> Vec<(usize, f32)> {
+    let mut fused: HashMap<usize, f32> = HashMap::new();
+
+    for list in ranked_lists {
+        for (rank, &doc_idx) in list.iter().enumerate() {
+            *fused.entry(doc_idx).or_insert(0.0) += 1.0 / (k + (rank + 1) as f32);
+        }
+    }
+
+    fused.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bm25_favors_exact_keyword_match() {
+        let tokenizer = MultiLanguageTokenizer::new();
+        let docs = [
+            "Elara the mage cast a fire spell",
+            "The weather today is cloudy with a chance of rain",
+        ];
+        let index = Bm25Index::build(&docs, &tokenizer);
+
+        let results = index.search("Elara", &tokenizer);
+        let best = results.iter().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert_eq!(best.0, 0);
+    }
+
+    #[test]
+    fn test_rrf_boosts_docs_ranked_highly_in_multiple_lists() {
+        let dense = vec![2, 0, 1];
+        let lexical = vec![0, 2, 1];
+
+        let fused = reciprocal_rank_fusion(&[dense, lexical], 60.0);
+        let best = fused
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(idx, _)| *idx)
+            .unwrap();
+
+        // doc 0 and doc 2 both rank in the top two of both lists; either is a
+        // reasonable winner, but doc 1 (always last) should not win.
+        assert_ne!(best, 1);
+    }
+}
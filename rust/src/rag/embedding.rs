@@ -5,94 +5,96 @@
 use ndarray::Array1;
 use std::collections::HashMap;
 
+use crate::normalize::Normalizer;
+
 /// Embedding trait
 pub trait Embedding: Send + Sync {
     fn embed(&self, text: &str) -> Array1<f32>;
     fn dimension(&self) -> usize;
 }
 
-/// Simple TF-IDF based embedder
-/// Fast and lightweight, good for keyword matching
+/// Character n-gram sizes hashed into the embedding space.
+/// Unigrams alone can't tell "魔法使い" from "使い魔法"; n-grams restore order sensitivity.
+const NGRAM_SIZES: [usize; 3] = [1, 2, 3];
+
+/// TF-IDF embedder over hashed character n-grams (the hashing trick).
+///
+/// Without `fit`, every n-gram gets `idf = 1.0` (a uniform bag-of-n-grams). Call `fit`
+/// with a representative corpus (e.g. a project's bible/character docs) to learn real
+/// document-frequency-based IDF weights.
 pub struct SimpleEmbedder {
     dimension: usize,
-    vocab: HashMap<char, usize>,
-    idf: HashMap<char, f32>,
+    idf: Vec<f32>,
+    fitted: bool,
+    normalizer: Normalizer,
 }
 
 impl SimpleEmbedder {
     pub fn new(dimension: usize) -> Self {
-        let mut vocab = HashMap::new();
-
-        // Initialize with common characters
-        // ASCII
-        for c in 'a'..='z' {
-            vocab.insert(c, vocab.len());
-        }
-        for c in '0'..='9' {
-            vocab.insert(c, vocab.len());
-        }
-
-        // Hiragana
-        for c in '\u{3040}'..='\u{309F}' {
-            vocab.insert(c, vocab.len());
-        }
-
-        // Katakana
-        for c in '\u{30A0}'..='\u{30FF}' {
-            vocab.insert(c, vocab.len());
+        Self {
+            dimension,
+            idf: vec![1.0; dimension],
+            fitted: false,
+            normalizer: Normalizer::new(),
         }
+    }
 
-        // Common Kanji (subset)
-        let common_kanji = "日一国会人年大十二本中長出三同時分上東生国会入見月白明書行気小".chars();
-        for c in common_kanji {
-            vocab.insert(c, vocab.len());
-        }
+    /// Use a custom normalization pipeline instead of the default (NFKC + lowercase).
+    pub fn with_normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
 
-        // Pad to dimension
-        while vocab.len() < dimension {
-            vocab.insert('\0', vocab.len());
+    /// Learn IDF weights from a corpus of documents.
+    ///
+    /// For each hashed feature, `idf = ln((N + 1) / (df + 1)) + 1`, where `df` is the
+    /// number of documents the feature (an n-gram, possibly collided with others via
+    /// hashing) appears in and `N` is the corpus size. Smoothed so unseen features and
+    /// features seen in every document both stay finite and positive.
+    pub fn fit(&mut self, corpus: &[&str]) {
+        if corpus.is_empty() {
+            return;
         }
 
-        // Simple IDF (can be improved with corpus analysis)
-        let mut idf = HashMap::new();
-        for (c, _) in &vocab {
-            // Lower IDF for common characters
-            let freq = match c {
-                ' ' | '。' | '、' | '.' | ',' => 1.0,
-                'の' | 'に' | 'は' | 'を' | 'が' | 'と' => 1.5,
-                'a' | 'e' | 'i' | 'o' | 'u' | 't' | 'n' => 1.5,
-                _ => 2.0,
-            };
-            idf.insert(*c, freq);
+        let mut doc_freq = vec![0u32; self.dimension];
+        for doc in corpus {
+            let normalized = self.normalizer.normalize(doc);
+            let mut seen = vec![false; self.dimension];
+            for (idx, _sign) in ngram_hashes(&normalized, self.dimension) {
+                if !seen[idx] {
+                    seen[idx] = true;
+                    doc_freq[idx] += 1;
+                }
+            }
         }
 
-        Self {
-            dimension,
-            vocab,
-            idf,
+        let n = corpus.len() as f32;
+        for (idx, df) in doc_freq.into_iter().enumerate() {
+            self.idf[idx] = ((n + 1.0) / (df as f32 + 1.0)).ln() + 1.0;
         }
+        self.fitted = true;
     }
 
     /// Embed text to vector
     pub fn embed(&self, text: &str) -> Array1<f32> {
-        let mut vector = Array1::zeros(self.dimension);
+        let normalized = self.normalizer.normalize(text);
+        let mut vector = Array1::<f32>::zeros(self.dimension);
 
-        // Character frequency
-        let mut char_counts: HashMap<char, usize> = HashMap::new();
-        for c in text.chars() {
-            *char_counts.entry(c).or_insert(0) += 1;
+        // Signed sum per bucket: same-sign occurrences of a feature reinforce each
+        // other and opposite-sign collisions between unrelated features partially
+        // cancel, instead of every occurrence after the first being counted unsigned.
+        let mut counts: HashMap<usize, f32> = HashMap::new();
+        let mut total = 0usize;
+        for (idx, sign) in ngram_hashes(&normalized, self.dimension) {
+            *counts.entry(idx).or_insert(0.0) += sign as f32;
+            total += 1;
         }
 
-        // TF-IDF weighting
-        let total_chars = text.chars().count().max(1);
-        for (c, count) in char_counts {
-            if let Some(&idx) = self.vocab.get(&c) {
-                if idx < self.dimension {
-                    let tf = count as f32 / total_chars as f32;
-                    let idf = self.idf.get(&c).copied().unwrap_or(1.0);
-                    vector[idx] = tf * idf;
-                }
-            }
+        let total = total.max(1) as f32;
+        for (idx, signed_count) in counts {
+            let tf = signed_count / total;
+            let idf = self.idf[idx];
+            vector[idx] += tf * idf;
         }
 
         // L2 normalize
@@ -103,6 +105,53 @@ impl SimpleEmbedder {
             vector
         }
     }
+
+    /// Whether `fit` has been called with a non-empty corpus.
+    pub fn is_fitted(&self) -> bool {
+        self.fitted
+    }
+}
+
+/// FNV-1a hash, used both to pick a feature's slot and (via a second bit) its sign.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+/// Slide character 1-3-grams over `text`, hashing each into `(idx, sign)` via the
+/// hashing trick: `idx = h % dimension`, with a second hash bit deciding `sign` so
+/// unrelated collisions partially cancel instead of always accumulating.
+fn ngram_hashes(text: &str, dimension: usize) -> Vec<(usize, i8)> {
+    if dimension == 0 {
+        return Vec::new();
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = Vec::with_capacity(chars.len() * NGRAM_SIZES.len());
+    let mut buf = String::new();
+
+    for &n in &NGRAM_SIZES {
+        if chars.len() < n {
+            continue;
+        }
+        for window in chars.windows(n) {
+            buf.clear();
+            buf.extend(window.iter());
+            let h = fnv1a(buf.as_bytes());
+            let idx = (h % dimension as u64) as usize;
+            let sign = if (h >> 63) & 1 == 0 { 1 } else { -1 };
+            out.push((idx, sign));
+        }
+    }
+
+    out
 }
 
 impl Embedding for SimpleEmbedder {
@@ -172,4 +221,43 @@ mod tests {
         let similarity = ja1.dot(&ja2);
         assert!(similarity > 0.0);
     }
+
+    #[test]
+    fn test_fit_lowers_idf_for_common_ngrams() {
+        let mut embedder = SimpleEmbedder::new(256);
+        let corpus = vec![
+            "the quick brown fox",
+            "the lazy dog sleeps",
+            "the sun is bright",
+        ];
+        embedder.fit(&corpus);
+        assert!(embedder.is_fitted());
+
+        // "the " appears in every document, so its IDF should settle near the floor.
+        let common_idx = embedder
+            .idf
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(idx, _)| idx)
+            .unwrap();
+        assert!(embedder.idf[common_idx] < 2.0);
+    }
+
+    #[test]
+    fn test_word_order_distinguished_by_ngrams() {
+        let embedder = SimpleEmbedder::new(256);
+
+        let forward = embedder.embed("魔法使い");
+        let reversed = embedder.embed("使い魔法");
+        let unrelated = embedder.embed("completely different text");
+
+        let sim_reordered = forward.dot(&reversed);
+        let sim_unrelated = forward.dot(&unrelated);
+
+        // Same characters in a different order should be closer to themselves than to
+        // unrelated text, but not identical (bigrams/trigrams differ).
+        assert!(sim_reordered > sim_unrelated);
+        assert!(sim_reordered < 0.999);
+    }
 }
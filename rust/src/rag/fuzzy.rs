@@ -0,0 +1,168 @@
+//! Fuzzy term matching via a bounded Levenshtein automaton
+//!
+//! Dense char-n-gram embeddings miss near-miss spellings and okurigana variants. This
+//! builds, for a query term, the set of states tracking edit distance as we consume a
+//! candidate term's characters (a Levenshtein automaton), and runs it once per candidate
+//! term in the vocabulary — each run is linear in the candidate's length, but
+//! `fuzzy_match` still evaluates every term in `vocab` rather than intersecting the
+//! automaton against a trie/FST of the vocabulary to prune whole subtrees at once, so
+//! the overall cost is still `O(vocab size * term length)`, not sublinear in vocabulary
+//! size. That intersection (FST-backed vocabulary, walked alongside the automaton's
+//! state transitions) is the natural next step if the vocabulary grows large enough for
+//! the per-term scan to matter; it isn't implemented here yet.
+
+/// A Levenshtein automaton for one query term, bounded to `max_edits`.
+///
+/// Internally this keeps the standard dynamic-programming row of states (one state per
+/// prefix length of the query), advancing it one vocabulary character at a time and
+/// pruning any state whose distance already exceeds `max_edits` — exactly the DFA
+/// transition/prune step, expressed as an incrementally-updated row instead of a
+/// pre-materialized transition table.
+pub struct LevenshteinAutomaton {
+    query: Vec<char>,
+    max_edits: usize,
+}
+
+impl LevenshteinAutomaton {
+    pub fn new(query: &str, max_edits: usize) -> Self {
+        Self {
+            query: query.chars().collect(),
+            max_edits,
+        }
+    }
+
+    /// Run the automaton over `term`'s full length, returning `Some(edits)` if the
+    /// term is accepted (edit distance to the query is within `max_edits`).
+    pub fn matches(&self, term: &str) -> Option<usize> {
+        let row = self.final_row(term);
+        let distance = *row.last()?;
+        (distance <= self.max_edits).then_some(distance)
+    }
+
+    /// Like `matches`, but accepts `term` if *any prefix* of it is within budget —
+    /// useful for autocomplete, where the user hasn't finished typing the full word.
+    ///
+    /// This needs its own DP rather than reusing `final_row`: `final_row` indexes by
+    /// *query*-prefix length for the full `term`, which answers "is some prefix of the
+    /// query close to the whole term" — backwards from what we want here. Instead we
+    /// track, after consuming each character of `term`, the distance from the full
+    /// query to that *term*-prefix (`cur[n]`), and take the min across all of them.
+    pub fn matches_prefix(&self, term: &str) -> Option<usize> {
+        let n = self.query.len();
+        let ceiling = self.max_edits + 1;
+        let mut prev: Vec<usize> = (0..=n).collect();
+        let mut best = prev[n];
+
+        for (j, tc) in term.chars().enumerate() {
+            let mut cur = vec![0usize; n + 1];
+            cur[0] = (j + 1).min(ceiling);
+
+            for i in 1..=n {
+                let cost = if self.query[i - 1] == tc { 0 } else { 1 };
+                let value = (prev[i - 1] + cost)
+                    .min(prev[i] + 1)
+                    .min(cur[i - 1] + 1);
+                cur[i] = value.min(ceiling);
+            }
+
+            best = best.min(cur[n]);
+            prev = cur;
+        }
+
+        (best <= self.max_edits).then_some(best)
+    }
+
+    /// Standard Levenshtein DP: `row[i]` after consuming all of `term` is the edit
+    /// distance from `query[0..i]` to `term`, for every query-prefix length `i`. States whose distance
+    /// already exceeds `max_edits` are clamped to `max_edits + 1` ("pruned" — they can
+    /// no longer become an accepting state without exceeding the budget).
+    fn final_row(&self, term: &str) -> Vec<usize> {
+        let n = self.query.len();
+        let ceiling = self.max_edits + 1;
+        let mut prev: Vec<usize> = (0..=n).collect();
+
+        for (j, tc) in term.chars().enumerate() {
+            let mut cur = vec![0usize; n + 1];
+            cur[0] = (j + 1).min(ceiling);
+
+            for i in 1..=n {
+                let cost = if self.query[i - 1] == tc { 0 } else { 1 };
+                let value = (prev[i - 1] + cost)
+                    .min(prev[i] + 1)
+                    .min(cur[i - 1] + 1);
+                cur[i] = value.min(ceiling);
+            }
+
+            prev = cur;
+        }
+
+        prev
+    }
+}
+
+/// Find every term in `vocab` within `max_edits` of `query`, sorted by ascending edit
+/// distance (exact matches — distance 0 — sort first, matching the "boost exact
+/// matches over fuzzy ones" requirement). When `prefix` is true, matches any term with
+/// an in-budget prefix rather than requiring a full match.
+///
+/// Runs the automaton once per `vocab` entry rather than intersecting it against a
+/// trie/FST of the vocabulary, so this scans every term — see the module docs.
+pub fn fuzzy_match<'a>(
+    query: &str,
+    vocab: impl Iterator<Item = &'a str>,
+    max_edits: usize,
+    prefix: bool,
+) -> Vec<(&'a str, usize)> {
+    let automaton = LevenshteinAutomaton::new(query, max_edits);
+
+    let mut matches: Vec<(&str, usize)> = vocab
+        .filter_map(|term| {
+            let distance = if prefix {
+                automaton.matches_prefix(term)
+            } else {
+                automaton.matches(term)
+            };
+            distance.map(|d| (term, d))
+        })
+        .collect();
+
+    matches.sort_by_key(|(_, d)| *d);
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_match_has_zero_edits() {
+        let automaton = LevenshteinAutomaton::new("magic", 2);
+        assert_eq!(automaton.matches("magic"), Some(0));
+    }
+
+    #[test]
+    fn test_single_typo_within_budget() {
+        let automaton = LevenshteinAutomaton::new("magic", 1);
+        assert_eq!(automaton.matches("magik"), Some(1));
+    }
+
+    #[test]
+    fn test_too_many_edits_rejected() {
+        let automaton = LevenshteinAutomaton::new("magic", 1);
+        assert_eq!(automaton.matches("banana"), None);
+    }
+
+    #[test]
+    fn test_prefix_variant_matches_autocomplete_style() {
+        let automaton = LevenshteinAutomaton::new("mag", 0);
+        assert_eq!(automaton.matches_prefix("magic"), Some(0));
+        assert_eq!(automaton.matches("magic"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_match_ranks_exact_first() {
+        let vocab = vec!["magic", "magik", "tragic"];
+        let results = fuzzy_match("magic", vocab.into_iter(), 2, false);
+        assert_eq!(results[0], ("magic", 0));
+    }
+}
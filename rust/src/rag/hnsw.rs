@@ -0,0 +1,303 @@
+//! HNSW (Hierarchical Navigable Small World) approximate nearest-neighbor index
+//!
+//! `Retriever::search`'s brute-force `embeddings.dot(&query_emb)` scan is fine for a
+//! few thousand documents but becomes the dominant cost once a long series'
+//! bible/chapters/facts grow large. This builds an optional multi-layer proximity
+//! graph as an alternative backend: insertion is greedy-descend-then-beam-search, and
+//! so is query. Embeddings are assumed L2-normalized, so cosine similarity is just the
+//! dot product (higher is closer).
+
+use ndarray::Array1;
+
+/// Tuning knobs for graph construction and search.
+#[derive(Debug, Clone, Copy)]
+pub struct HnswConfig {
+    /// Max neighbors a node selects per layer when it is inserted (paper's `M`). Back-links
+    /// added to existing nodes by later insertions are not pruned, so an existing node's
+    /// neighbor list can grow past `m`.
+    pub m: usize,
+    /// Candidate list size during insertion (paper's `ef_construction`).
+    pub ef_construction: usize,
+    /// Candidate list size during search (paper's `ef`), unless overridden per-query.
+    pub ef_search: usize,
+    /// Level-generation scale factor (paper's `mL`), typically `1 / ln(M)`.
+    pub m_l: f32,
+}
+
+impl Default for HnswConfig {
+    fn default() -> Self {
+        let m = 16;
+        Self {
+            m,
+            ef_construction: 200,
+            ef_search: 64,
+            m_l: 1.0 / (m as f32).ln(),
+        }
+    }
+}
+
+struct HnswNode {
+    vector: Array1<f32>,
+    /// `neighbors[layer]` is that node's neighbor list at `layer`.
+    neighbors: Vec<Vec<usize>>,
+}
+
+/// An HNSW graph over L2-normalized vectors, with `Array1` node index == `Retriever`
+/// document index so callers can map results straight back to documents.
+pub struct HnswIndex {
+    nodes: Vec<HnswNode>,
+    entry_point: Option<usize>,
+    config: HnswConfig,
+    /// Deterministic PRNG state (xorshift64*), seeded with a fixed constant so level
+    /// assignment is reproducible across runs without pulling in a `rand` dependency.
+    rng_state: u64,
+}
+
+impl HnswIndex {
+    pub fn new(config: HnswConfig) -> Self {
+        Self {
+            nodes: Vec::new(),
+            entry_point: None,
+            config,
+            rng_state: 0x9E3779B97F4A7C15,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn next_uniform(&mut self) -> f32 {
+        // xorshift64* — cheap, deterministic, good enough for level assignment.
+        let mut x = self.rng_state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.rng_state = x;
+        let bits = x.wrapping_mul(0x2545F4914F6CDD1D);
+        ((bits >> 11) as f64 / (1u64 << 53) as f64) as f32
+    }
+
+    fn random_level(&mut self) -> usize {
+        let uniform = self.next_uniform().max(f32::MIN_POSITIVE);
+        (-uniform.ln() * self.config.m_l).floor() as usize
+    }
+
+    fn distance(&self, a: &Array1<f32>, b: &Array1<f32>) -> f32 {
+        // Higher dot product = more similar; we want a "distance" so smaller = closer.
+        -a.dot(b)
+    }
+
+    /// Greedy best-first search within a single layer, returning up to `ef` closest
+    /// node indices to `query`, explored starting from `entry_points`.
+    fn search_layer(
+        &self,
+        query: &Array1<f32>,
+        entry_points: &[usize],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<(usize, f32)> {
+        use std::collections::HashSet;
+
+        let mut visited: HashSet<usize> = entry_points.iter().copied().collect();
+        let mut candidates: Vec<(usize, f32)> = entry_points
+            .iter()
+            .map(|&idx| (idx, self.distance(query, &self.nodes[idx].vector)))
+            .collect();
+        candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let mut best = candidates.clone();
+
+        while let Some((current, current_dist)) = candidates.first().copied() {
+            candidates.remove(0);
+
+            let worst_best = best.last().map(|(_, d)| *d).unwrap_or(f32::INFINITY);
+            if current_dist > worst_best && best.len() >= ef {
+                break;
+            }
+
+            let Some(node) = self.nodes.get(current) else {
+                continue;
+            };
+            let Some(neighbors) = node.neighbors.get(layer) else {
+                continue;
+            };
+
+            for &neighbor in neighbors {
+                if visited.contains(&neighbor) {
+                    continue;
+                }
+                visited.insert(neighbor);
+
+                let dist = self.distance(query, &self.nodes[neighbor].vector);
+                let worst_best = best.last().map(|(_, d)| *d).unwrap_or(f32::INFINITY);
+                if dist < worst_best || best.len() < ef {
+                    candidates.push((neighbor, dist));
+                    candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+                    best.push((neighbor, dist));
+                    best.sort_by(|a, b| a.1.total_cmp(&b.1));
+                    best.truncate(ef);
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Robust-prune heuristic from the HNSW paper: walk `candidates` closest-first and
+    /// keep one only if it's closer to `query` than to every neighbor already kept —
+    /// this is what gives the graph diversity instead of clustering all edges toward
+    /// one dense region.
+    fn select_neighbors(&self, query: &Array1<f32>, mut candidates: Vec<(usize, f32)>, m: usize) -> Vec<usize> {
+        candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let mut selected: Vec<usize> = Vec::with_capacity(m);
+
+        for (candidate, dist_to_query) in candidates {
+            if selected.len() >= m {
+                break;
+            }
+            let dominated = selected.iter().any(|&kept| {
+                self.distance(&self.nodes[candidate].vector, &self.nodes[kept].vector) < dist_to_query
+            });
+            if !dominated {
+                selected.push(candidate);
+            }
+        }
+
+        selected
+    }
+
+    /// Insert `vector`, returning its node index (== document index for callers that
+    /// keep the two in lockstep).
+    pub fn insert(&mut self, vector: Array1<f32>) -> usize {
+        let level = self.random_level();
+        let idx = self.nodes.len();
+        self.nodes.push(HnswNode {
+            vector,
+            neighbors: vec![Vec::new(); level + 1],
+        });
+
+        let Some(entry) = self.entry_point else {
+            self.entry_point = Some(idx);
+            return idx;
+        };
+
+        let entry_level = self.nodes[entry].neighbors.len() - 1;
+        let query = self.nodes[idx].vector.clone();
+
+        let mut current_nearest = entry;
+        for layer in ((level + 1)..=entry_level).rev() {
+            let result = self.search_layer(&query, &[current_nearest], 1, layer);
+            if let Some((best, _)) = result.first() {
+                current_nearest = *best;
+            }
+        }
+
+        let mut entry_points = vec![current_nearest];
+        for layer in (0..=level.min(entry_level)).rev() {
+            let candidates = self.search_layer(&query, &entry_points, self.config.ef_construction, layer);
+            let neighbors = self.select_neighbors(&query, candidates, self.config.m);
+
+            self.nodes[idx].neighbors[layer] = neighbors.clone();
+            for &neighbor in &neighbors {
+                let neighbor_layers = self.nodes[neighbor].neighbors.len();
+                if layer < neighbor_layers {
+                    self.nodes[neighbor].neighbors[layer].push(idx);
+                }
+            }
+
+            entry_points = neighbors;
+            if entry_points.is_empty() {
+                entry_points = vec![current_nearest];
+            }
+        }
+
+        if level > entry_level {
+            self.entry_point = Some(idx);
+        }
+
+        idx
+    }
+
+    /// Approximate k-nearest-neighbor search, returning `(doc_idx, cosine_similarity)`
+    /// sorted best-first.
+    pub fn search(&self, query: &Array1<f32>, top_k: usize) -> Vec<(usize, f32)> {
+        let Some(entry) = self.entry_point else {
+            return Vec::new();
+        };
+        if self.nodes.is_empty() {
+            return Vec::new();
+        }
+
+        let entry_level = self.nodes[entry].neighbors.len() - 1;
+        let mut current_nearest = entry;
+        for layer in (1..=entry_level).rev() {
+            let result = self.search_layer(query, &[current_nearest], 1, layer);
+            if let Some((best, _)) = result.first() {
+                current_nearest = *best;
+            }
+        }
+
+        let ef = self.config.ef_search.max(top_k);
+        let mut results = self.search_layer(query, &[current_nearest], ef, 0);
+        results.truncate(top_k);
+        // search_layer returns distance (negative dot); convert back to similarity.
+        results.into_iter().map(|(idx, dist)| (idx, -dist)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalized(values: &[f32]) -> Array1<f32> {
+        let v = Array1::from(values.to_vec());
+        let norm = v.dot(&v).sqrt();
+        if norm > 0.0 {
+            v / norm
+        } else {
+            v
+        }
+    }
+
+    #[test]
+    fn test_insert_and_search_finds_nearest() {
+        let mut index = HnswIndex::new(HnswConfig::default());
+
+        let target = normalized(&[1.0, 0.0, 0.0]);
+        index.insert(target.clone());
+        index.insert(normalized(&[0.0, 1.0, 0.0]));
+        index.insert(normalized(&[0.0, 0.0, 1.0]));
+        index.insert(normalized(&[0.9, 0.1, 0.0]));
+
+        let results = index.search(&target, 2);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, 0);
+    }
+
+    #[test]
+    fn test_search_empty_index_returns_empty() {
+        let index = HnswIndex::new(HnswConfig::default());
+        let results = index.search(&normalized(&[1.0, 0.0]), 5);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_insert_many_keeps_graph_connected() {
+        let mut index = HnswIndex::new(HnswConfig::default());
+        for i in 0..200 {
+            let angle = i as f32 * 0.01;
+            index.insert(normalized(&[angle.cos(), angle.sin(), 0.0]));
+        }
+
+        let query = normalized(&[1.0, 0.0, 0.0]);
+        let results = index.search(&query, 10);
+        assert_eq!(results.len(), 10);
+        assert!(results.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+}
@@ -10,11 +10,40 @@ use ndarray::{Array1, Array2, ArrayView1};
 use parking_lot::RwLock;
 use rayon::prelude::*;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 
+pub mod bm25;
+pub mod classifier;
 pub mod embedding;
+pub mod fuzzy;
+pub mod hnsw;
 
+pub use bm25::{reciprocal_rank_fusion, Bm25Index};
+pub use fuzzy::{fuzzy_match, LevenshteinAutomaton};
+pub use hnsw::{HnswConfig, HnswIndex};
+pub use classifier::DocTypeClassifier;
 pub use embedding::{Embedding, SimpleEmbedder};
 
+use crate::i18n::Language;
+use crate::tokenizer::MultiLanguageTokenizer;
+
+/// Which signal(s) `Retriever::search_with_mode` should rank by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Cosine similarity over `SimpleEmbedder` vectors only (the original behavior).
+    Dense,
+    /// BM25 over the tokenized inverted index only.
+    Lexical,
+    /// Both rankings fused with Reciprocal Rank Fusion (`k = 60`).
+    Hybrid,
+}
+
+const RRF_K: f32 = 60.0;
+
+/// Below this many documents, the exact brute-force scan beats HNSW's graph-walk
+/// overhead, so `search` falls back to it even when an HNSW backend is configured.
+const HNSW_MIN_CORPUS: usize = 1000;
+
 /// Document for RAG
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Document {
@@ -27,7 +56,7 @@ pub struct Document {
     pub embedding: Option<Array1<f32>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DocType {
     Bible,
@@ -52,6 +81,14 @@ pub struct Retriever {
     embeddings: RwLock<Option<Array2<f32>>>,
     embedder: SimpleEmbedder,
     dimension: usize,
+    classifier: RwLock<Option<DocTypeClassifier>>,
+    tokenizer: MultiLanguageTokenizer,
+    bm25: RwLock<Option<Bm25Index>>,
+    hnsw: RwLock<Option<HnswIndex>>,
+    /// Bumped on every document mutation (add/clear); compared against
+    /// `built_generation` so callers can detect a stale index via `is_stale`.
+    generation: AtomicU64,
+    built_generation: RwLock<u64>,
 }
 
 impl Retriever {
@@ -61,13 +98,80 @@ impl Retriever {
             embeddings: RwLock::new(None),
             embedder: SimpleEmbedder::new(dimension),
             dimension,
+            classifier: RwLock::new(None),
+            tokenizer: MultiLanguageTokenizer::new(),
+            bm25: RwLock::new(None),
+            hnsw: RwLock::new(None),
+            generation: AtomicU64::new(0),
+            built_generation: RwLock::new(u64::MAX),
+        }
+    }
+
+    /// Build a retriever that incrementally maintains an HNSW graph alongside the
+    /// dense matrix, used automatically by `search`/`search_with_mode` once the corpus
+    /// grows past [`HNSW_MIN_CORPUS`] documents.
+    pub fn with_hnsw(dimension: usize, config: HnswConfig) -> Self {
+        let mut retriever = Self::new(dimension);
+        retriever.hnsw = RwLock::new(Some(HnswIndex::new(config)));
+        retriever
+    }
+
+    /// Override the tokenizer used to build/query the BM25 lexical index (default is
+    /// `MultiLanguageTokenizer::new()`). Indexing already script-segments mixed CJK/Latin
+    /// content and applies width-folding/lowercasing via `tokenize_canonical`, so this is
+    /// for callers who need a custom `Normalizer` or `Stemmer` (e.g. `with_stemmer` tuned
+    /// for a specific corpus) rather than a different tokenization strategy outright.
+    pub fn with_tokenizer(mut self, tokenizer: MultiLanguageTokenizer) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    /// Install a trained `DocTypeClassifier`. Once set, `add_document`/`add_documents`
+    /// will use it to predict `doc_type` for documents whose type is `DocType::Other`.
+    pub fn set_classifier(&self, classifier: DocTypeClassifier) {
+        *self.classifier.write() = Some(classifier);
+    }
+
+    /// If `doc_type` is `Other` and a classifier is installed, predict the real type.
+    fn maybe_classify(&self, doc: &mut Document) {
+        if doc.doc_type != DocType::Other {
+            return;
+        }
+        if let Some(classifier) = self.classifier.read().as_ref() {
+            if let Some((predicted, _confidence)) = classifier.classify(&doc.content) {
+                doc.doc_type = predicted;
+            }
+        }
+    }
+
+    /// Detect `doc.content`'s language via character-script statistics and record it in
+    /// `doc.metadata` (`"language"` as a BCP-47 code, `"language_confidence"` as the
+    /// detector's confidence), unless a caller already set `"language"` explicitly.
+    /// `MultiLanguageTokenizer` already script-segments mixed-language content per
+    /// token during indexing, so this doesn't change tokenization directly — it's a
+    /// per-document signal for callers that want to route UI/i18n by corpus content.
+    fn maybe_detect_language(&self, doc: &mut Document) {
+        if doc.metadata.contains_key("language") {
+            return;
+        }
+        if let Some((language, confidence)) = Language::detect(&doc.content) {
+            doc.metadata.insert("language".to_string(), language.code().to_string());
+            doc.metadata
+                .insert("language_confidence".to_string(), confidence.to_string());
         }
     }
 
     /// Add document
     pub fn add_document(&self, mut doc: Document) {
+        self.maybe_classify(&mut doc);
+        self.maybe_detect_language(&mut doc);
+
         // Generate embedding
         let embedding = self.embedder.embed(&doc.content);
+
+        if let Some(hnsw) = self.hnsw.write().as_mut() {
+            hnsw.insert(embedding.clone());
+        }
         doc.embedding = Some(embedding);
 
         let mut docs = self.documents.write();
@@ -75,6 +179,7 @@ impl Retriever {
 
         // Invalidate embeddings matrix
         *self.embeddings.write() = None;
+        self.generation.fetch_add(1, Ordering::Relaxed);
     }
 
     /// Add multiple documents (parallel)
@@ -87,36 +192,74 @@ impl Retriever {
         let docs_with_embeddings: Vec<_> = docs
             .into_par_iter()
             .map(|mut doc| {
+                self.maybe_classify(&mut doc);
+                self.maybe_detect_language(&mut doc);
                 let embedding = self.embedder.embed(&doc.content);
                 doc.embedding = Some(embedding);
                 doc
             })
             .collect();
 
+        // HNSW insertion is inherently sequential (each insert's greedy descent depends
+        // on the graph built by prior inserts), so it's done here rather than in the
+        // parallel map above.
+        if let Some(hnsw) = self.hnsw.write().as_mut() {
+            for doc in &docs_with_embeddings {
+                if let Some(emb) = &doc.embedding {
+                    hnsw.insert(emb.clone());
+                }
+            }
+        }
+
         let mut docs_lock = self.documents.write();
-        let was_empty = docs_lock.is_empty();
+        let n_old = docs_lock.len();
         docs_lock.extend(docs_with_embeddings);
 
-        if was_empty {
-            // Fast path: when index was empty, materialize embedding matrix now.
-            let n_docs = docs_lock.len();
-            let mut data = Vec::with_capacity(n_docs * self.dimension);
-            for doc in docs_lock.iter() {
-                if let Some(emb) = &doc.embedding {
-                    data.extend(emb.iter().copied());
-                } else {
-                    data.extend(std::iter::repeat_n(0.0f32, self.dimension));
+        let mut embeddings_lock = self.embeddings.write();
+        match embeddings_lock.take() {
+            // Matrix already covers the prior docs: grow it in place by appending the
+            // new rows, so `build()` after this only has to do O(new docs) work instead
+            // of re-embedding (and re-flattening) the whole corpus.
+            Some(existing) if existing.nrows() == n_old && existing.ncols() == self.dimension => {
+                let mut data = existing.into_raw_vec();
+                data.reserve(docs_lock.len().saturating_sub(n_old) * self.dimension);
+                for doc in docs_lock.iter().skip(n_old) {
+                    if let Some(emb) = &doc.embedding {
+                        data.extend(emb.iter().copied());
+                    } else {
+                        data.extend(std::iter::repeat_n(0.0f32, self.dimension));
+                    }
                 }
+                *embeddings_lock = Array2::from_shape_vec((docs_lock.len(), self.dimension), data).ok();
             }
-
-            let matrix = Array2::from_shape_vec((n_docs, self.dimension), data).ok();
-            *self.embeddings.write() = matrix;
-        } else {
-            *self.embeddings.write() = None;
+            // No matrix and no prior documents (first batch): materialize fresh from
+            // the new documents. A matrix invalidated by `add_document` falls through
+            // to the arm below.
+            None if n_old == 0 => {
+                let n_docs = docs_lock.len();
+                let mut data = Vec::with_capacity(n_docs * self.dimension);
+                for doc in docs_lock.iter() {
+                    if let Some(emb) = &doc.embedding {
+                        data.extend(emb.iter().copied());
+                    } else {
+                        data.extend(std::iter::repeat_n(0.0f32, self.dimension));
+                    }
+                }
+                *embeddings_lock = Array2::from_shape_vec((n_docs, self.dimension), data).ok();
+            }
+            // Stale/mismatched matrix: leave invalidated, `build()` will rebuild fully.
+            _ => *embeddings_lock = None,
         }
+        drop(embeddings_lock);
+        drop(docs_lock);
+
+        self.generation.fetch_add(1, Ordering::Relaxed);
     }
 
-    /// Build index (create embeddings matrix)
+    /// Build index (create embeddings matrix and BM25 index). Cheap to call repeatedly:
+    /// if nothing changed since the last build, it's a no-op; if `add_documents` already
+    /// grew the embeddings matrix incrementally, only BM25 (which has no incremental
+    /// path) gets rebuilt.
     pub fn build(&self) {
         let docs = self.documents.read();
 
@@ -124,77 +267,175 @@ impl Retriever {
             return;
         }
 
-        if let Some(existing) = self.embeddings.read().as_ref() {
-            if existing.nrows() == docs.len() && existing.ncols() == self.dimension {
-                return;
-            }
+        let current_gen = self.generation.load(Ordering::Relaxed);
+        if !self.is_stale() {
+            return;
         }
 
-        // Build embeddings matrix
-        let n_docs = docs.len();
-        let mut embeddings = Array2::zeros((n_docs, self.dimension));
+        let matrix_current = self
+            .embeddings
+            .read()
+            .as_ref()
+            .map(|e| e.nrows() == docs.len() && e.ncols() == self.dimension)
+            .unwrap_or(false);
 
-        for (i, doc) in docs.iter().enumerate() {
-            if let Some(emb) = &doc.embedding {
-                embeddings.row_mut(i).assign(emb);
+        if !matrix_current {
+            let n_docs = docs.len();
+            let mut embeddings = Array2::zeros((n_docs, self.dimension));
+
+            for (i, doc) in docs.iter().enumerate() {
+                if let Some(emb) = &doc.embedding {
+                    embeddings.row_mut(i).assign(emb);
+                }
             }
+
+            *self.embeddings.write() = Some(embeddings);
         }
 
-        *self.embeddings.write() = Some(embeddings);
+        let contents: Vec<&str> = docs.iter().map(|d| d.content.as_str()).collect();
+        *self.bm25.write() = Some(Bm25Index::build(&contents, &self.tokenizer));
+
+        *self.built_generation.write() = current_gen;
     }
 
-    /// Search with cosine similarity
-    pub fn search(&self, query: &str, top_k: usize) -> Vec<SearchResult> {
+    /// Whether documents have been added/removed since the last `build()`, meaning
+    /// `search`'s dense/lexical indexes no longer reflect the current corpus.
+    pub fn is_stale(&self) -> bool {
+        *self.built_generation.read() != self.generation.load(Ordering::Relaxed)
+    }
+
+    /// Convenience wrapper for `search_with_mode(query, top_k, SearchMode::Hybrid)`:
+    /// dense cosine and lexical BM25 rankings fused with Reciprocal Rank Fusion. This
+    /// is the mode authors should reach for by default — it recovers proper nouns the
+    /// char-level embedder smears out without losing dense recall on paraphrases.
+    pub fn search_hybrid(&self, query: &str, top_k: usize) -> Vec<SearchResult> {
+        self.search_with_mode(query, top_k, SearchMode::Hybrid)
+    }
+
+    /// Search using the given `SearchMode` (dense cosine, lexical BM25, or both fused
+    /// with Reciprocal Rank Fusion). `Dense` is equivalent to [`Retriever::search`].
+    pub fn search_with_mode(&self, query: &str, top_k: usize, mode: SearchMode) -> Vec<SearchResult> {
         if top_k == 0 {
             return Vec::new();
         }
 
-        let query_emb = self.embedder.embed(query);
-
         let docs = self.documents.read();
-        let embeddings_lock = self.embeddings.read();
+        if docs.is_empty() {
+            return Vec::new();
+        }
+
+        let dense_ranked = || -> Vec<usize> {
+            let mut dense = self.score_dense(query, &docs);
+            select_top_k(&mut dense, docs.len());
+            dense.into_iter().map(|(idx, _)| idx).collect()
+        };
+        let lexical_ranked = || -> Vec<usize> {
+            let bm25_lock = self.bm25.read();
+            let Some(bm25) = bm25_lock.as_ref() else {
+                return Vec::new();
+            };
+            let mut lexical = bm25.search(query, &self.tokenizer);
+            select_top_k(&mut lexical, docs.len());
+            lexical.into_iter().map(|(idx, _)| idx).collect()
+        };
+
+        let mut results: Vec<(usize, f32)> = match mode {
+            SearchMode::Dense => return self.search_dense_top_k(query, top_k, &docs),
+            SearchMode::Lexical => {
+                let bm25_lock = self.bm25.read();
+                bm25_lock
+                    .as_ref()
+                    .map(|bm25| bm25.search(query, &self.tokenizer))
+                    .unwrap_or_default()
+            }
+            SearchMode::Hybrid => {
+                reciprocal_rank_fusion(&[dense_ranked(), lexical_ranked()], RRF_K)
+            }
+        };
+
+        select_top_k(&mut results, top_k);
+
+        results
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (idx, score))| SearchResult {
+                doc: docs[idx].clone(),
+                score,
+                rank: rank + 1,
+            })
+            .collect()
+    }
 
+    /// Search with fuzzy term expansion: each query token is matched against the BM25
+    /// vocabulary within `max_edits` edits (or by in-budget prefix, for autocomplete),
+    /// and the matched terms are appended to the query before running BM25 scoring.
+    /// Exact matches are naturally boosted since they also appear verbatim in `query`.
+    pub fn search_fuzzy(
+        &self,
+        query: &str,
+        top_k: usize,
+        max_edits: usize,
+        prefix: bool,
+    ) -> Vec<SearchResult> {
+        if top_k == 0 {
+            return Vec::new();
+        }
+
+        let docs = self.documents.read();
         if docs.is_empty() {
             return Vec::new();
         }
 
-        // For small corpora, sequential iteration avoids parallel scheduling overhead.
-        let use_parallel = docs.len() >= 2048;
+        let bm25_lock = self.bm25.read();
+        let Some(bm25) = bm25_lock.as_ref() else {
+            return Vec::new();
+        };
 
-        // Compute (index, similarity) directly to avoid intermediate allocations.
-        let mut results: Vec<(usize, f32)> = if let Some(embeddings) = embeddings_lock.as_ref() {
-            // Embeddings are L2-normalized; cosine similarity is dot product.
-            let sims = embeddings.dot(&query_emb);
-            sims.iter().enumerate().map(|(idx, score)| (idx, *score)).collect()
-        } else if use_parallel {
-            docs.par_iter()
-                .enumerate()
-                .map(|(idx, doc)| {
-                    let score = doc
-                        .embedding
-                        .as_ref()
-                        .map(|emb| query_emb.dot(emb))
-                        .unwrap_or(0.0);
-                    (idx, score)
-                })
-                .collect()
-        } else {
-            docs.iter()
-                .enumerate()
-                .map(|(idx, doc)| {
-                    let score = doc
-                        .embedding
-                        .as_ref()
-                        .map(|emb| query_emb.dot(emb))
-                        .unwrap_or(0.0);
-                    (idx, score)
-                })
-                .collect()
+        let mut expanded = query.to_string();
+        for token in self.tokenizer.tokenize_canonical(query) {
+            if token.text.trim().is_empty() {
+                continue;
+            }
+            let matches = fuzzy_match(&token.text, bm25.vocabulary(), max_edits, prefix);
+            for (term, _edits) in matches {
+                expanded.push(' ');
+                expanded.push_str(term);
+            }
+        }
+
+        let mut results = bm25.search(&expanded, &self.tokenizer);
+        select_top_k(&mut results, top_k);
+
+        results
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (idx, score))| SearchResult {
+                doc: docs[idx].clone(),
+                score,
+                rank: rank + 1,
+            })
+            .collect()
+    }
+
+    /// Dense top-k lookup: uses the HNSW graph when one is configured and the corpus
+    /// is past [`HNSW_MIN_CORPUS`] (where the graph-walk pays for itself), otherwise
+    /// falls back to the exact brute-force scan.
+    fn search_dense_top_k(&self, query: &str, top_k: usize, docs: &[Document]) -> Vec<SearchResult> {
+        let query_emb = self.embedder.embed(query);
+
+        let hnsw_lock = self.hnsw.read();
+        let mut results = match hnsw_lock.as_ref() {
+            Some(hnsw) if hnsw.len() >= HNSW_MIN_CORPUS => hnsw.search(&query_emb, top_k),
+            _ => {
+                drop(hnsw_lock);
+                let mut scored = self.score_dense(query, docs);
+                select_top_k(&mut scored, top_k);
+                scored
+            }
         };
 
         select_top_k(&mut results, top_k);
 
-        // Convert to SearchResult
         results
             .into_iter()
             .enumerate()
@@ -206,6 +447,49 @@ impl Retriever {
             .collect()
     }
 
+    fn score_dense(&self, query: &str, docs: &[Document]) -> Vec<(usize, f32)> {
+        let query_emb = self.embedder.embed(query);
+        let embeddings_lock = self.embeddings.read();
+
+        if let Some(embeddings) = embeddings_lock.as_ref() {
+            // Embeddings are L2-normalized; cosine similarity is dot product.
+            let sims = embeddings.dot(&query_emb);
+            sims.iter().enumerate().map(|(idx, score)| (idx, *score)).collect()
+        } else {
+            let score_one = |(idx, doc): (usize, &Document)| {
+                let score = doc
+                    .embedding
+                    .as_ref()
+                    .map(|emb| query_emb.dot(emb))
+                    .unwrap_or(0.0);
+                (idx, score)
+            };
+            // For small corpora, sequential iteration avoids parallel scheduling overhead.
+            if docs.len() >= 2048 {
+                docs.par_iter().enumerate().map(score_one).collect()
+            } else {
+                docs.iter().enumerate().map(score_one).collect()
+            }
+        }
+    }
+
+    /// Search with cosine similarity. Equivalent to
+    /// `search_with_mode(query, top_k, SearchMode::Dense)`: uses the HNSW graph index
+    /// when one is configured (`with_hnsw`) and the corpus is past `HNSW_MIN_CORPUS`,
+    /// otherwise an exact brute-force scan over the embeddings matrix.
+    pub fn search(&self, query: &str, top_k: usize) -> Vec<SearchResult> {
+        if top_k == 0 {
+            return Vec::new();
+        }
+
+        let docs = self.documents.read();
+        if docs.is_empty() {
+            return Vec::new();
+        }
+
+        self.search_dense_top_k(query, top_k, &docs)
+    }
+
     /// Search by document type
     pub fn search_by_type(
         &self,
@@ -273,7 +557,95 @@ impl Retriever {
     pub fn clear(&self) {
         self.documents.write().clear();
         *self.embeddings.write() = None;
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Serialize documents (with their embedding vectors) and the embedder dimension to
+    /// `path`, so a warm-started process can skip re-embedding the whole corpus.
+    /// BM25/HNSW indexes aren't persisted; call `build()` after `load` to rebuild the
+    /// BM25 index. A loaded retriever has no HNSW graph.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> crate::Result<()> {
+        let docs = self.documents.read();
+        let snapshot = PersistedIndex {
+            dimension: self.dimension,
+            documents: docs
+                .iter()
+                .map(|doc| PersistedDocument {
+                    id: doc.id.clone(),
+                    content: doc.content.clone(),
+                    source: doc.source.clone(),
+                    doc_type: doc.doc_type,
+                    metadata: doc.metadata.clone(),
+                    embedding: doc.embedding.as_ref().map(|e| e.iter().copied().collect()),
+                })
+                .collect(),
+        };
+
+        let json = serde_json::to_string(&snapshot)?;
+        std::fs::write(path, json)?;
+        Ok(())
     }
+
+    /// Load a `Retriever` previously written by `save`. Documents and their embedding
+    /// vectors are restored directly (no re-embedding), and the dense matrix is
+    /// materialized eagerly so `search` works immediately; call `build()` afterward to
+    /// populate the BM25 index for lexical/hybrid search.
+    pub fn load(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        let snapshot: PersistedIndex = serde_json::from_str(&json)?;
+
+        let retriever = Self::new(snapshot.dimension);
+        let docs: Vec<Document> = snapshot
+            .documents
+            .into_iter()
+            .map(|doc| Document {
+                id: doc.id,
+                content: doc.content,
+                source: doc.source,
+                doc_type: doc.doc_type,
+                metadata: doc.metadata,
+                embedding: doc.embedding.map(Array1::from_vec),
+            })
+            .collect();
+
+        let n_docs = docs.len();
+        let mut data = Vec::with_capacity(n_docs * retriever.dimension);
+        for doc in &docs {
+            if let Some(emb) = &doc.embedding {
+                data.extend(emb.iter().copied());
+            } else {
+                data.extend(std::iter::repeat_n(0.0f32, retriever.dimension));
+            }
+        }
+
+        *retriever.documents.write() = docs;
+        if n_docs > 0 {
+            *retriever.embeddings.write() =
+                Array2::from_shape_vec((n_docs, retriever.dimension), data).ok();
+        }
+        retriever.generation.fetch_add(1, Ordering::Relaxed);
+
+        Ok(retriever)
+    }
+}
+
+/// On-disk shape for `Retriever::save`/`load`: documents plus their embedding vectors
+/// (as a plain `Vec<f32>`, so this doesn't depend on `ndarray`'s serde feature) and the
+/// embedder dimension they were built against.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PersistedIndex {
+    dimension: usize,
+    documents: Vec<PersistedDocument>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PersistedDocument {
+    id: String,
+    content: String,
+    source: String,
+    doc_type: DocType,
+    metadata: HashMap<String, String>,
+    embedding: Option<Vec<f32>>,
 }
 
 /// Fast cosine similarity using SIMD
@@ -393,6 +765,287 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[test]
+    fn test_hnsw_backend_finds_similar_documents_at_scale() {
+        let retriever = Retriever::with_hnsw(32, HnswConfig::default());
+
+        for i in 0..(HNSW_MIN_CORPUS + 50) {
+            retriever.add_document(Document {
+                id: i.to_string(),
+                content: format!("filler chapter content number {}", i),
+                source: "bench.md".to_string(),
+                doc_type: DocType::Chapter,
+                metadata: HashMap::new(),
+                embedding: None,
+            });
+        }
+        retriever.add_document(Document {
+            id: "target".to_string(),
+            content: "magic power flows through the ancient ley lines".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+
+        // Confirm the corpus is actually large enough to take the HNSW branch in
+        // `search_dense_top_k`, rather than silently falling back to brute force.
+        {
+            let hnsw_lock = retriever.hnsw.read();
+            let hnsw = hnsw_lock.as_ref().expect("with_hnsw should install an HNSW index");
+            assert!(hnsw.len() >= HNSW_MIN_CORPUS);
+        }
+
+        let results = retriever.search_with_mode("magic power ley lines", 5, SearchMode::Dense);
+        assert!(!results.is_empty());
+        assert!(results.iter().any(|r| r.doc.id == "target"));
+    }
+
+    #[test]
+    fn test_search_hybrid_matches_search_with_mode_hybrid() {
+        let retriever = Retriever::new(128);
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "Zylqor is a rare artifact".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+        retriever.build();
+
+        let via_wrapper = retriever.search_hybrid("Zylqor", 3);
+        assert!(!via_wrapper.is_empty());
+        assert_eq!(via_wrapper[0].doc.id, "1");
+
+        let via_mode = retriever.search_with_mode("Zylqor", 3, SearchMode::Hybrid);
+        let ids = |results: &[SearchResult]| results.iter().map(|r| r.doc.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&via_wrapper[..]), ids(&via_mode[..]));
+    }
+
+    #[test]
+    fn test_hybrid_search_finds_exact_proper_noun() {
+        let retriever = Retriever::new(128);
+
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "Zylqor is a rare artifact forged by dwarves".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+
+        for i in 0..5 {
+            retriever.add_document(Document {
+                id: format!("filler{}", i),
+                content: format!("some unrelated chapter text number {}", i),
+                source: "chapters".to_string(),
+                doc_type: DocType::Chapter,
+                metadata: HashMap::new(),
+                embedding: None,
+            });
+        }
+
+        retriever.build();
+
+        let results = retriever.search_with_mode("Zylqor", 3, SearchMode::Hybrid);
+        assert!(!results.is_empty());
+        assert!(results.iter().any(|r| r.doc.id == "1"));
+    }
+
+    #[test]
+    fn test_add_document_detects_and_records_language() {
+        let retriever = Retriever::new(32);
+
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "魔法の世界について学ぶ物語".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+        retriever.add_document(Document {
+            id: "2".to_string(),
+            content: "This is worldbuilding prose in English".to_string(),
+            source: "notes.md".to_string(),
+            doc_type: DocType::Fact,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+
+        let docs = retriever.documents.read();
+        assert_eq!(docs[0].metadata.get("language"), Some(&"ja".to_string()));
+        assert_eq!(docs[1].metadata.get("language"), Some(&"en".to_string()));
+    }
+
+    #[test]
+    fn test_add_document_respects_explicit_language_metadata() {
+        let retriever = Retriever::new(32);
+        let mut metadata = HashMap::new();
+        metadata.insert("language".to_string(), "ko".to_string());
+
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "English content, but the caller already tagged this as Korean".to_string(),
+            source: "notes.md".to_string(),
+            doc_type: DocType::Fact,
+            metadata,
+            embedding: None,
+        });
+
+        let docs = retriever.documents.read();
+        assert_eq!(docs[0].metadata.get("language"), Some(&"ko".to_string()));
+    }
+
+    #[test]
+    fn test_hybrid_search_segments_japanese_query_without_spaces() {
+        let retriever = Retriever::new(32);
+
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "魔法の世界について学ぶ物語".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+        retriever.add_document(Document {
+            id: "2".to_string(),
+            content: "今日の天気は晴れです".to_string(),
+            source: "chapters".to_string(),
+            doc_type: DocType::Chapter,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+
+        retriever.build();
+
+        let results = retriever.search_with_mode("魔法の世界", 3, SearchMode::Hybrid);
+        assert!(!results.is_empty());
+        assert!(results.iter().any(|r| r.doc.id == "1"));
+    }
+
+    #[test]
+    fn test_with_tokenizer_override_strips_diacritics_for_bm25() {
+        let tokenizer =
+            MultiLanguageTokenizer::new().with_normalizer(crate::Normalizer::new().with_strip_diacritics(true));
+        let retriever = Retriever::new(32).with_tokenizer(tokenizer);
+
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "the café is a rare refuge for writers".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+        retriever.build();
+
+        let results = retriever.search_with_mode("cafe", 3, SearchMode::Hybrid);
+        assert!(results.iter().any(|r| r.doc.id == "1"));
+    }
+
+    #[test]
+    fn test_search_fuzzy_recovers_typo() {
+        let retriever = Retriever::new(128);
+
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "Zylqor is a rare artifact".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+
+        retriever.build();
+
+        let results = retriever.search_fuzzy("Zyqor", 3, 2, false);
+        assert!(results.iter().any(|r| r.doc.id == "1"));
+    }
+
+    #[test]
+    fn test_is_stale_tracks_build_and_mutation() {
+        let retriever = Retriever::new(32);
+        assert!(retriever.is_stale());
+
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "magic and lore".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+        assert!(retriever.is_stale());
+
+        retriever.build();
+        assert!(!retriever.is_stale());
+
+        retriever.add_document(Document {
+            id: "2".to_string(),
+            content: "more lore".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+        assert!(retriever.is_stale());
+    }
+
+    #[test]
+    fn test_add_documents_grows_matrix_incrementally_then_build_refreshes_bm25() {
+        let retriever = Retriever::new(32);
+        retriever.add_documents(vec![Document {
+            id: "1".to_string(),
+            content: "Zylqor the artifact".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        }]);
+        retriever.build();
+
+        retriever.add_documents(vec![Document {
+            id: "2".to_string(),
+            content: "Marrow the swordsman".to_string(),
+            source: "chars.md".to_string(),
+            doc_type: DocType::Character,
+            metadata: HashMap::new(),
+            embedding: None,
+        }]);
+        retriever.build();
+
+        let results = retriever.search_with_mode("Marrow", 3, SearchMode::Lexical);
+        assert!(results.iter().any(|r| r.doc.id == "2"));
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip_preserves_search() {
+        let retriever = Retriever::new(32);
+        retriever.add_document(Document {
+            id: "1".to_string(),
+            content: "magic flows through ley lines".to_string(),
+            source: "bible.md".to_string(),
+            doc_type: DocType::Bible,
+            metadata: HashMap::new(),
+            embedding: None,
+        });
+        retriever.build();
+
+        let path = std::env::temp_dir().join(format!("novelist_retriever_test_{}.json", std::process::id()));
+        retriever.save(&path).unwrap();
+
+        let loaded = Retriever::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.len(), 1);
+        let results = loaded.search("magic", 1);
+        assert_eq!(results[0].doc.id, "1");
+    }
+
     #[test]
     fn test_search_by_type_ranks_within_subset() {
         let retriever = Retriever::new(128);
@@ -0,0 +1,210 @@
+//! Dictionary-based Chinese word segmentation (DAG + Viterbi best-path)
+//!
+//! Chinese has no whitespace between words, so `tokenize_generic`'s word-boundary
+//! split treats an entire CJK run as one blob. This builds a directed acyclic graph of
+//! every dictionary word starting at each position, then finds the maximum-likelihood
+//! path right-to-left — the same DAG + dynamic-programming approach jieba uses.
+
+use std::collections::HashMap;
+
+use super::{Token, TokenType};
+
+/// The longest dictionary word considered when building the DAG, in characters.
+const MAX_WORD_LEN: usize = 4;
+
+/// Word -> log-frequency dictionary for max-probability segmentation.
+pub struct ChineseDictionary {
+    log_freq: HashMap<String, f32>,
+    /// Score assigned to an unrecognized single character, so segmentation always
+    /// terminates even over out-of-vocabulary text.
+    floor: f32,
+}
+
+impl ChineseDictionary {
+    pub fn from_word_freqs(word_freqs: &[(&str, u32)]) -> Self {
+        let total: u32 = word_freqs.iter().map(|(_, f)| *f).sum::<u32>().max(1);
+        let log_freq = word_freqs
+            .iter()
+            .map(|(w, f)| (w.to_string(), (*f as f32 / total as f32).ln()))
+            .collect();
+
+        Self {
+            log_freq,
+            floor: (0.5 / total as f32).ln(),
+        }
+    }
+
+    /// A small bundled sample dictionary, enough to segment common phrases. Production
+    /// use should load a fuller word-frequency list (e.g. derived from a jieba dict).
+    pub fn bundled() -> Self {
+        Self::from_word_freqs(&[
+            ("你好", 800),
+            ("世界", 600),
+            ("中国", 900),
+            ("北京", 500),
+            ("魔法", 200),
+            ("小说", 300),
+            ("人工", 150),
+            ("智能", 150),
+            ("人工智能", 400),
+            ("今天", 500),
+            ("天气", 400),
+            ("我们", 700),
+            ("他们", 500),
+            ("故事", 300),
+            ("世界上", 100),
+        ])
+    }
+
+    fn score(&self, word: &str) -> f32 {
+        self.log_freq.get(word).copied().unwrap_or(self.floor)
+    }
+}
+
+impl Default for ChineseDictionary {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+/// Chinese tokenizer doing dictionary max-probability (DAG + Viterbi) segmentation.
+pub struct ChineseTokenizer {
+    dict: ChineseDictionary,
+}
+
+impl ChineseTokenizer {
+    pub fn new() -> Self {
+        Self {
+            dict: ChineseDictionary::bundled(),
+        }
+    }
+
+    pub fn with_dictionary(dict: ChineseDictionary) -> Self {
+        Self { dict }
+    }
+
+    fn segment(&self, text: &str) -> Vec<(usize, usize)> {
+        let char_starts: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        let chars: Vec<char> = text.chars().collect();
+        let n = chars.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        // route[i] = (best cumulative log-score from i to the end, end index of the
+        // first word chosen at i). Computed right-to-left so each decision only needs
+        // already-solved suffixes.
+        let mut route: Vec<(f32, usize)> = vec![(0.0, n); n + 1];
+
+        for i in (0..n).rev() {
+            let mut best_score = f32::NEG_INFINITY;
+            let mut best_end = i + 1;
+
+            for len in 1..=MAX_WORD_LEN.min(n - i) {
+                let end = i + len;
+                let word: String = chars[i..end].iter().collect();
+                // Only a single character may fall back to the floor score; an unknown
+                // multi-character span is not a candidate word.
+                if len > 1 && !self.dict.log_freq.contains_key(&word) {
+                    continue;
+                }
+                let score = self.dict.score(&word) + route[end].0;
+                if score > best_score {
+                    best_score = score;
+                    best_end = end;
+                }
+            }
+
+            route[i] = (best_score, best_end);
+        }
+
+        let byte_end = text.len();
+        let mut spans = Vec::new();
+        let mut i = 0;
+        while i < n {
+            let j = route[i].1;
+            let start_byte = char_starts[i];
+            let end_byte = char_starts.get(j).copied().unwrap_or(byte_end);
+            spans.push((start_byte, end_byte));
+            i = j;
+        }
+        spans
+    }
+}
+
+impl Default for ChineseTokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::Tokenizer for ChineseTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        self.segment(text)
+            .into_iter()
+            .map(|(start, end)| {
+                let surface = &text[start..end];
+                let token_type = if surface.chars().all(|c| c.is_ascii_punctuation()) {
+                    TokenType::Punctuation
+                } else if surface.chars().all(|c| c.is_whitespace()) {
+                    TokenType::Space
+                } else {
+                    TokenType::Word
+                };
+                Token {
+                    text: surface.to_string(),
+                    start,
+                    end,
+                    token_type,
+                }
+            })
+            .collect()
+    }
+
+    fn estimate_tokens(&self, text: &str) -> usize {
+        text.chars().count() * 2 / 3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    #[test]
+    fn test_segments_known_dictionary_words() {
+        let tokenizer = ChineseTokenizer::new();
+        let tokens = tokenizer.tokenize("你好世界");
+
+        let words: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(words, vec!["你好", "世界"]);
+    }
+
+    #[test]
+    fn test_prefers_longer_compound_word() {
+        let tokenizer = ChineseTokenizer::new();
+        let tokens = tokenizer.tokenize("人工智能");
+
+        // "人工智能" itself is in the dictionary with high frequency, so it should win
+        // over segmenting into "人工" + "智能".
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, "人工智能");
+    }
+
+    #[test]
+    fn test_unknown_characters_fall_back_to_single_chars() {
+        let tokenizer = ChineseTokenizer::new();
+        let tokens = tokenizer.tokenize("魔");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, "魔");
+    }
+
+    #[test]
+    fn test_byte_offsets_cover_text() {
+        let tokenizer = ChineseTokenizer::new();
+        let text = "你好世界";
+        let tokens = tokenizer.tokenize(text);
+        assert_eq!(tokens.first().unwrap().start, 0);
+        assert_eq!(tokens.last().unwrap().end, text.len());
+    }
+}
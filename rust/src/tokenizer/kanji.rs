@@ -0,0 +1,206 @@
+//! Kanji reading and JLPT/grade enrichment
+//!
+//! `Token`s from `JapaneseTokenizer` only carry surface text, which is enough for
+//! indexing but not for furigana generation or readability grading. This attaches a
+//! kana reading and a difficulty rating (JLPT level, school grade) to word tokens,
+//! looked up from a bundled per-codepoint kanji table that callers can override.
+
+use std::collections::HashMap;
+
+use super::Token;
+
+/// Per-kanji metadata: kana reading(s), JLPT level (1 = N1/hardest .. 5 = N5/easiest),
+/// and the school grade it's taught in Japan (1-6 = elementary, 8 = secondary/other).
+#[derive(Debug, Clone)]
+pub struct KanjiInfo {
+    pub readings: Vec<String>,
+    pub jlpt_level: Option<u8>,
+    pub grade: Option<u8>,
+}
+
+/// A table of kanji metadata, keyed by codepoint. Ships with a small bundled subset;
+/// callers with a fuller dataset (e.g. KANJIDIC2) can build their own via `from_map`.
+pub struct KanjiTable {
+    entries: HashMap<char, KanjiInfo>,
+}
+
+impl KanjiTable {
+    pub fn from_map(entries: HashMap<char, KanjiInfo>) -> Self {
+        Self { entries }
+    }
+
+    pub fn get(&self, c: char) -> Option<&KanjiInfo> {
+        self.entries.get(&c)
+    }
+
+    /// A small bundled subset covering common kanji, enough to work out of the box.
+    /// Ship a fuller KANJIDIC2-derived table and build with `from_map` for production use.
+    pub fn bundled() -> Self {
+        let mut entries = HashMap::new();
+        let data: &[(char, &[&str], u8, u8)] = &[
+            ('日', &["にち", "ひ"], 5, 1),
+            ('一', &["いち"], 5, 1),
+            ('人', &["ひと", "じん"], 5, 1),
+            ('年', &["ねん"], 5, 1),
+            ('大', &["だい", "おお"], 5, 1),
+            ('本', &["ほん"], 5, 1),
+            ('国', &["くに"], 5, 2),
+            ('中', &["なか", "ちゅう"], 5, 1),
+            ('長', &["なが", "ちょう"], 4, 2),
+            ('出', &["で", "だ"], 4, 1),
+            ('見', &["み"], 5, 1),
+            ('言', &["い", "げん"], 4, 2),
+            ('生', &["せい", "い"], 5, 1),
+            ('魔', &["ま"], 1, 8),
+            ('法', &["ほう"], 3, 4),
+            ('力', &["ちから", "りょく"], 5, 1),
+            ('使', &["つか", "し"], 3, 3),
+        ];
+        for (c, readings, jlpt, grade) in data {
+            entries.insert(
+                *c,
+                KanjiInfo {
+                    readings: readings.iter().map(|s| s.to_string()).collect(),
+                    jlpt_level: Some(*jlpt),
+                    grade: Some(*grade),
+                },
+            );
+        }
+        Self { entries }
+    }
+}
+
+impl Default for KanjiTable {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+/// A `Token` enriched with reading and difficulty metadata, for furigana generation
+/// and readability scoring. Kept as a parallel struct rather than extra `Token` fields
+/// so plain tokenization stays cheap when enrichment isn't needed.
+#[derive(Debug, Clone)]
+pub struct TokenInfo {
+    pub token: Token,
+    /// Kana reading of the first kanji in the token that the table rates (that kanji's
+    /// first listed reading), if any. Readings of the token's other characters are not
+    /// included, so a multi-kanji word gets only its first kanji's reading.
+    pub reading: Option<String>,
+    /// Hardest JLPT level (1 = N1 .. 5 = N5) among the kanji the token contains.
+    pub jlpt_level: Option<u8>,
+    /// Highest school grade among the kanji the token contains.
+    pub grade: Option<u8>,
+}
+
+/// Attach reading/JLPT/grade metadata to each token using `table`.
+pub fn enrich(tokens: &[Token], table: &KanjiTable) -> Vec<TokenInfo> {
+    tokens
+        .iter()
+        .map(|token| {
+            let mut jlpt_level: Option<u8> = None;
+            let mut grade = None;
+            let mut reading = None;
+
+            for c in token.text.chars() {
+                if let Some(info) = table.get(c) {
+                    // Lower JLPT number = harder (1 = N1 .. 5 = N5), so the "hardest
+                    // kanji wins" rule takes the min of the *numbers*, not `Option::max`
+                    // (which would pick the easiest). `Option::max`'s `None < Some(_)`
+                    // ordering is still exactly what we want here, though: it means
+                    // "any rated kanji beats no info yet", so it's only the direction of
+                    // the `Some`-to-`Some` comparison that needs to flip.
+                    jlpt_level = match (jlpt_level, info.jlpt_level) {
+                        (Some(a), Some(b)) => Some(a.min(b)),
+                        (current, new) => current.max(new),
+                    };
+                    grade = grade.max(info.grade);
+                    if reading.is_none() {
+                        reading = info.readings.first().cloned();
+                    }
+                }
+            }
+
+            TokenInfo {
+                token: token.clone(),
+                reading,
+                jlpt_level,
+                grade,
+            }
+        })
+        .collect()
+}
+
+/// Aggregate per-token difficulty into a single document-level readability score:
+/// the average JLPT level over every rated kanji character in `text`, where a
+/// lower score (closer to 1/N1) means harder text. Returns `None` for text with no
+/// rated kanji (e.g. pure kana/English).
+pub fn readability_score(text: &str, table: &KanjiTable) -> Option<f32> {
+    let rated_levels: Vec<u8> = text
+        .chars()
+        .filter_map(|c| table.get(c).and_then(|info| info.jlpt_level))
+        .collect();
+
+    if rated_levels.is_empty() {
+        return None;
+    }
+
+    Some(rated_levels.iter().map(|&l| l as f32).sum::<f32>() / rated_levels.len() as f32)
+}
+
+/// Furigana pairs (surface, reading) for word tokens that have a known reading.
+pub fn furigana(infos: &[TokenInfo]) -> Vec<(String, String)> {
+    infos
+        .iter()
+        .filter_map(|info| {
+            info.reading
+                .as_ref()
+                .map(|r| (info.token.text.clone(), r.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::TokenType;
+
+    fn token(text: &str) -> Token {
+        Token {
+            text: text.to_string(),
+            start: 0,
+            end: text.len(),
+            token_type: TokenType::Word,
+        }
+    }
+
+    #[test]
+    fn test_enrich_attaches_reading_and_difficulty() {
+        let table = KanjiTable::bundled();
+        let infos = enrich(&[token("魔法")], &table);
+
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].jlpt_level, Some(1)); // min(1, 3) from 魔(N1) and 法(N3): hardest wins
+        assert!(infos[0].reading.is_some());
+    }
+
+    #[test]
+    fn test_readability_score_averages_rated_kanji() {
+        let table = KanjiTable::bundled();
+        let score = readability_score("日本語", &table).unwrap();
+        assert!(score > 0.0);
+    }
+
+    #[test]
+    fn test_readability_score_none_for_unrated_text() {
+        let table = KanjiTable::bundled();
+        assert_eq!(readability_score("hello", &table), None);
+    }
+
+    #[test]
+    fn test_furigana_pairs_surface_with_reading() {
+        let table = KanjiTable::bundled();
+        let infos = enrich(&[token("日")], &table);
+        let pairs = furigana(&infos);
+        assert_eq!(pairs[0].0, "日");
+    }
+}
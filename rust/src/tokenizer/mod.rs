@@ -3,9 +3,31 @@
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_normalization::UnicodeNormalization;
 
+use crate::normalize::Normalizer;
+
+pub mod chinese;
+pub mod detect;
 pub mod japanese;
+pub mod kanji;
+pub mod stem;
 
+pub use chinese::{ChineseDictionary, ChineseTokenizer};
+pub use detect::{LanguageIdentifier, ScriptKind};
 pub use japanese::JapaneseTokenizer;
+pub use kanji::{KanjiInfo, KanjiTable, TokenInfo};
+pub use stem::{NoopStemmer, PorterStemmer, Stemmer};
+
+/// A byte-offset span of text tagged with its detected language, alongside the tokens.
+/// Produced by [`MultiLanguageTokenizer::tokenize_with_lang`] so downstream RAG can
+/// filter or weight passages by language.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageSpan {
+    pub start: usize,
+    pub end: usize,
+    /// `None` means the script run's language could not be identified with confidence
+    /// (e.g. digits/punctuation, or Latin text below the LID confidence threshold).
+    pub language: Option<&'static str>,
+}
 
 /// Token representation
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -16,6 +38,16 @@ pub struct Token {
     pub token_type: TokenType,
 }
 
+/// A [`Token`] alongside its normalized/stemmed form, produced by
+/// [`MultiLanguageTokenizer::tokenize_normalized`]. `text`/byte offsets still refer to
+/// the original surface span (for highlighting); `normalized` is the canonical key a
+/// lexical index should store.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedToken {
+    pub token: Token,
+    pub normalized: String,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum TokenType {
     Word,
@@ -44,32 +76,149 @@ pub trait Tokenizer: Send + Sync {
 /// Fast multi-language tokenizer
 pub struct MultiLanguageTokenizer {
     ja_tokenizer: JapaneseTokenizer,
+    zh_tokenizer: ChineseTokenizer,
+    lid: LanguageIdentifier,
+    normalizer: Normalizer,
+    stemmer: Box<dyn Stemmer>,
 }
 
 impl MultiLanguageTokenizer {
     pub fn new() -> Self {
         Self {
             ja_tokenizer: JapaneseTokenizer::new(),
+            zh_tokenizer: ChineseTokenizer::new(),
+            lid: LanguageIdentifier::new(),
+            normalizer: Normalizer::new(),
+            stemmer: Box::new(PorterStemmer),
         }
     }
-    
-    /// Detect language from text
+
+    /// Use a custom normalization pipeline for [`MultiLanguageTokenizer::tokenize_canonical`].
+    pub fn with_normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Use a custom stemmer for [`MultiLanguageTokenizer::tokenize_normalized`]'s Latin
+    /// tokens (default is [`PorterStemmer`]). CJK tokens are never stemmed regardless
+    /// of this setting, since Porter-style suffix stripping doesn't apply to them.
+    pub fn with_stemmer(mut self, stemmer: impl Stemmer + 'static) -> Self {
+        self.stemmer = Box::new(stemmer);
+        self
+    }
+
+    /// Tokenize a normalized copy of `text` (NFKC, lowercased, and optionally
+    /// diacritic-stripped/kana-folded per the configured `Normalizer`). Token offsets
+    /// refer to the *normalized* string, not the original input, since normalization
+    /// can change byte lengths (e.g. full-width -> half-width). Use this when you only
+    /// need canonical token text (indexing, classification); use `tokenize`/
+    /// `tokenize_with_lang` when offsets into the original text matter (highlighting).
+    pub fn tokenize_canonical(&self, text: &str) -> Vec<Token> {
+        let normalized = self.normalizer.normalize(text);
+        self.tokenize_auto(&normalized)
+    }
+
+    /// Tokenize `text` with the original surface spans preserved, alongside a
+    /// normalized/stemmed key for each token: NFKC + lowercase via the configured
+    /// `Normalizer`, then Porter-style stemming (or whatever [`Stemmer`] was set via
+    /// `with_stemmer`) for non-CJK word tokens. CJK tokens are normalized but never
+    /// stemmed, since English suffix-stripping rules don't apply to them. Use this to
+    /// build a lexical index on `normalized` while still highlighting the original
+    /// `token.text` span.
+    pub fn tokenize_normalized(&self, text: &str) -> Vec<NormalizedToken> {
+        self.tokenize_auto(text)
+            .into_iter()
+            .map(|token| {
+                let folded = self.normalizer.normalize(&token.text);
+                let normalized = if token.token_type == TokenType::Word && !crate::normalize::contains_cjk(&folded) {
+                    self.stemmer.stem(&folded)
+                } else {
+                    folded
+                };
+                NormalizedToken { token, normalized }
+            })
+            .collect()
+    }
+
+    /// Tokenize a (possibly mixed-script) document by segmenting it into script runs,
+    /// routing Kana/Han runs to the Japanese tokenizer and Latin runs to the generic
+    /// word tokenizer, then merging the streams back in document order. Returns both
+    /// the merged tokens and the language span each run was tagged with.
+    pub fn tokenize_with_lang(&self, text: &str) -> (Vec<Token>, Vec<LanguageSpan>) {
+        let runs = detect::segment_scripts(text);
+        let mut tokens = Vec::new();
+        let mut spans = Vec::with_capacity(runs.len());
+
+        for run in runs {
+            let segment = &text[run.start..run.end];
+
+            let language = match run.script {
+                ScriptKind::Kana | ScriptKind::Han | ScriptKind::CjkPunctuation => Some("ja"),
+                ScriptKind::Hangul => Some("ko"),
+                ScriptKind::Latin => self.lid.identify(segment).0,
+                ScriptKind::Digit | ScriptKind::Other => None,
+            };
+            spans.push(LanguageSpan {
+                start: run.start,
+                end: run.end,
+                language,
+            });
+
+            let run_tokens = match run.script {
+                ScriptKind::Kana | ScriptKind::Han => self.ja_tokenizer.tokenize(segment),
+                _ => self.tokenize_generic(segment),
+            };
+
+            tokens.extend(run_tokens.into_iter().map(|mut t| {
+                t.start += run.start;
+                t.end += run.start;
+                t
+            }));
+        }
+
+        (tokens, spans)
+    }
+
+    /// Detect language from text via script composition.
+    ///
+    /// A flat "1/3 of characters" ratio misclassifies kanji-heavy, kana-light Japanese
+    /// as Chinese (no single threshold works for both scripts at once). Instead this
+    /// counts characters per script in a single allocation-free pass and applies
+    /// precedence rules: any kana at all forces Japanese (kana is exclusive to
+    /// Japanese, unlike kanji/Han which Chinese also uses). Otherwise the Hangul, Han
+    /// and Latin counts compete and the script with the highest count wins (Korean,
+    /// Chinese, English), ties going to Hangul and then Han. Text with none of these
+    /// scripts is English; no word-level check is made beyond the per-character counts.
     pub fn detect_language(text: &str) -> Language {
-        let ja_ratio = text.chars().filter(|c| is_japanese(*c)).count();
-        let zh_ratio = text.chars().filter(|c| is_chinese(*c)).count();
-        let ko_ratio = text.chars().filter(|c| is_korean(*c)).count();
-        
-        let total = text.chars().count().max(1);
-        
-        if ja_ratio * 3 > total {
-            Language::Japanese
-        } else if zh_ratio * 3 > total {
-            Language::Chinese
-        } else if ko_ratio * 3 > total {
-            Language::Korean
-        } else {
-            Language::English
+        let mut kana = 0usize;
+        let mut han = 0usize;
+        let mut hangul = 0usize;
+        let mut latin = 0usize;
+
+        for c in text.chars() {
+            match c as u32 {
+                0x3040..=0x309F | 0x30A0..=0x30FF => kana += 1,
+                0x4E00..=0x9FFF | 0x3400..=0x4DBF => han += 1,
+                0xAC00..=0xD7AF | 0x1100..=0x11FF | 0x3130..=0x318F => hangul += 1,
+                _ if c.is_ascii_alphabetic() => latin += 1,
+                _ => {}
+            }
+        }
+
+        if kana > 0 {
+            return Language::Japanese;
+        }
+        if hangul > 0 && hangul >= han && hangul >= latin {
+            return Language::Korean;
+        }
+        if han > 0 && han >= hangul && han >= latin {
+            return Language::Chinese;
+        }
+        if hangul > latin {
+            return Language::Korean;
         }
+
+        Language::English
     }
     
     /// Tokenize with auto language detection
@@ -78,6 +227,7 @@ impl MultiLanguageTokenizer {
         
         match lang {
             Language::Japanese => self.ja_tokenizer.tokenize(text),
+            Language::Chinese => self.zh_tokenizer.tokenize(text),
             _ => self.tokenize_generic(text),
         }
     }
@@ -154,30 +304,6 @@ impl Tokenizer for MultiLanguageTokenizer {
     }
 }
 
-// Language detection helpers
-fn is_japanese(c: char) -> bool {
-    matches!(c as u32,
-        0x3040..=0x309F | // Hiragana
-        0x30A0..=0x30FF | // Katakana
-        0x4E00..=0x9FFF   // Kanji
-    )
-}
-
-fn is_chinese(c: char) -> bool {
-    matches!(c as u32,
-        0x4E00..=0x9FFF | // CJK Unified
-        0x3400..=0x4DBF   // CJK Extension A
-    ) && !is_japanese(c)
-}
-
-fn is_korean(c: char) -> bool {
-    matches!(c as u32,
-        0xAC00..=0xD7AF | // Hangul Syllables
-        0x1100..=0x11FF | // Hangul Jamo
-        0x3130..=0x318F   // Hangul Compatibility
-    )
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,6 +324,73 @@ mod tests {
         assert_eq!(tokens[0].text, "Hello");
     }
     
+    #[test]
+    fn test_tokenize_with_lang_mixed_script() {
+        let tokenizer = MultiLanguageTokenizer::new();
+        let (tokens, spans) = tokenizer.tokenize_with_lang("Hello こんにちは");
+
+        assert!(!tokens.is_empty());
+        assert!(spans.iter().any(|s| s.language == Some("en")));
+        assert!(spans.iter().any(|s| s.language == Some("ja")));
+
+        // Byte offsets must stay within the original text.
+        for token in &tokens {
+            assert!(token.end <= "Hello こんにちは".len());
+        }
+    }
+
+    #[test]
+    fn test_detect_language_kanji_heavy_japanese_not_misread_as_chinese() {
+        // Mostly kanji, only one kana character — a flat 1/3 ratio would pick Chinese.
+        let text = "日本国内総生産統計調査結果";
+        let with_kana = format!("{}の", text);
+        assert_eq!(
+            MultiLanguageTokenizer::detect_language(&with_kana),
+            Language::Japanese
+        );
+    }
+
+    #[test]
+    fn test_detect_language_han_only_is_chinese() {
+        assert_eq!(
+            MultiLanguageTokenizer::detect_language("你好世界"),
+            Language::Chinese
+        );
+    }
+
+    #[test]
+    fn test_tokenize_auto_routes_chinese_to_dictionary_segmenter() {
+        let tokenizer = MultiLanguageTokenizer::new();
+        let tokens = tokenizer.tokenize_auto("你好世界");
+        let words: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(words, vec!["你好", "世界"]);
+    }
+
+    #[test]
+    fn test_tokenize_canonical_folds_fullwidth() {
+        let tokenizer = MultiLanguageTokenizer::new();
+        let tokens = tokenizer.tokenize_canonical("ＨＥＬＬＯ");
+        assert_eq!(tokens[0].text, "hello");
+    }
+
+    #[test]
+    fn test_tokenize_normalized_stems_english_and_preserves_offsets() {
+        let tokenizer = MultiLanguageTokenizer::new();
+        let text = "Running dogs";
+        let normalized = tokenizer.tokenize_normalized(text);
+
+        let running = &normalized[0];
+        assert_eq!(running.normalized, "run");
+        assert_eq!(&text[running.token.start..running.token.end], "Running");
+    }
+
+    #[test]
+    fn test_tokenize_normalized_leaves_cjk_unstemmed() {
+        let tokenizer = MultiLanguageTokenizer::new();
+        let normalized = tokenizer.tokenize_normalized("魔法使い");
+        assert!(normalized.iter().any(|t| t.normalized.contains('魔')));
+    }
+
     #[test]
     fn test_estimate_tokens() {
         let en = "This is a test sentence.";
@@ -0,0 +1,284 @@
+//! Stemming: collapses suffix-inflected surface forms ("running"/"runs") to a shared
+//! root so lexical indexing and token estimates don't treat them as unrelated terms.
+//! Irregular forms ("ran") are not mapped to their root.
+//!
+//! `Stemmer` is pluggable so callers can swap in a language-specific implementation;
+//! `PorterStemmer` implements the classic Porter algorithm for English, and
+//! `NoopStemmer` is the identity function for languages (CJK) where naive suffix
+//! stripping would corrupt the word.
+
+/// Reduces a word to its stem. Implementations should be cheap and allocation-light;
+/// callers run this per-token during `tokenize_normalized`.
+pub trait Stemmer: Send + Sync {
+    fn stem(&self, word: &str) -> String;
+}
+
+/// Identity stemmer for languages where English-style suffix stripping doesn't apply
+/// (Japanese, Chinese, Korean).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopStemmer;
+
+impl Stemmer for NoopStemmer {
+    fn stem(&self, word: &str) -> String {
+        word.to_string()
+    }
+}
+
+/// Classic Porter stemmer (Porter, 1980) for English.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PorterStemmer;
+
+fn is_vowel(chars: &[char], i: usize) -> bool {
+    match chars[i] {
+        'a' | 'e' | 'i' | 'o' | 'u' => true,
+        'y' => i > 0 && !is_vowel(chars, i - 1),
+        _ => false,
+    }
+}
+
+/// Measure `m`: the number of vowel-consonant sequences in the word, per Porter's
+/// definition of `[C](VC){m}[V]`.
+fn measure(chars: &[char]) -> usize {
+    let mut m = 0;
+    let mut prev_vowel = false;
+    let mut seen_consonant = false;
+    for i in 0..chars.len() {
+        let vowel = is_vowel(chars, i);
+        if seen_consonant && prev_vowel && !vowel {
+            m += 1;
+        }
+        if !vowel {
+            seen_consonant = true;
+        }
+        prev_vowel = vowel;
+    }
+    m
+}
+
+fn contains_vowel(chars: &[char]) -> bool {
+    (0..chars.len()).any(|i| is_vowel(chars, i))
+}
+
+/// `*d`: ends in a double consonant (e.g. "-tt", "-ss").
+fn ends_double_consonant(chars: &[char]) -> bool {
+    let n = chars.len();
+    n >= 2 && chars[n - 1] == chars[n - 2] && !is_vowel(chars, n - 1)
+}
+
+/// `*o`: ends `consonant-vowel-consonant` where the final consonant isn't w/x/y.
+fn ends_cvc(chars: &[char]) -> bool {
+    let n = chars.len();
+    if n < 3 {
+        return false;
+    }
+    !is_vowel(chars, n - 3)
+        && is_vowel(chars, n - 2)
+        && !is_vowel(chars, n - 1)
+        && !matches!(chars[n - 1], 'w' | 'x' | 'y')
+}
+
+fn ends_with(chars: &[char], suffix: &str) -> bool {
+    let suffix: Vec<char> = suffix.chars().collect();
+    chars.len() >= suffix.len() && chars[chars.len() - suffix.len()..] == suffix[..]
+}
+
+fn strip(chars: &[char], suffix_len: usize) -> Vec<char> {
+    chars[..chars.len() - suffix_len].to_vec()
+}
+
+/// Try each `(suffix, replacement, condition)` in order; apply the first whose suffix
+/// matches and whose condition holds on the stem with the suffix removed.
+fn apply_rules(chars: Vec<char>, rules: &[(&str, &str, fn(&[char]) -> bool)]) -> Vec<char> {
+    for (suffix, replacement, condition) in rules {
+        if ends_with(&chars, suffix) {
+            let stem = strip(&chars, suffix.chars().count());
+            if condition(&stem) {
+                let mut result = stem;
+                result.extend(replacement.chars());
+                return result;
+            }
+            // Matching suffix with a failing condition still stops the search (Porter's
+            // rules are tried in order and only the first matching suffix is considered).
+            return chars;
+        }
+    }
+    chars
+}
+
+fn always(_: &[char]) -> bool {
+    true
+}
+
+fn m_gt_0(chars: &[char]) -> bool {
+    measure(chars) > 0
+}
+
+fn m_gt_1(chars: &[char]) -> bool {
+    measure(chars) > 1
+}
+
+impl Stemmer for PorterStemmer {
+    fn stem(&self, word: &str) -> String {
+        let lower = word.to_lowercase();
+        let mut chars: Vec<char> = lower.chars().collect();
+        if chars.len() <= 2 {
+            return lower;
+        }
+
+        // Step 1a: plurals.
+        chars = apply_rules(
+            chars,
+            &[
+                ("sses", "ss", always as fn(&[char]) -> bool),
+                ("ies", "i", always),
+                ("ss", "ss", always),
+                ("s", "", always),
+            ],
+        );
+
+        // Step 1b: -eed/-ed/-ing, with a cleanup pass when -ed/-ing is removed.
+        let mut did_step_1b_2 = false;
+        if ends_with(&chars, "eed") {
+            let stem = strip(&chars, 3);
+            if measure(&stem) > 0 {
+                chars = stem;
+                chars.extend("ee".chars());
+            }
+        } else if ends_with(&chars, "ed") && contains_vowel(&strip(&chars, 2)) {
+            chars = strip(&chars, 2);
+            did_step_1b_2 = true;
+        } else if ends_with(&chars, "ing") && contains_vowel(&strip(&chars, 3)) {
+            chars = strip(&chars, 3);
+            did_step_1b_2 = true;
+        }
+
+        if did_step_1b_2 {
+            if ends_with(&chars, "at") || ends_with(&chars, "bl") || ends_with(&chars, "iz") {
+                chars.push('e');
+            } else if ends_double_consonant(&chars) && !matches!(chars[chars.len() - 1], 'l' | 's' | 'z') {
+                chars.pop();
+            } else if measure(&chars) == 1 && ends_cvc(&chars) {
+                chars.push('e');
+            }
+        }
+
+        // Step 1c: terminal y -> i if the stem contains a vowel.
+        if ends_with(&chars, "y") {
+            let stem = strip(&chars, 1);
+            if contains_vowel(&stem) {
+                chars = stem;
+                chars.push('i');
+            }
+        }
+
+        // Step 2: common double-suffixes, conditioned on m > 0.
+        chars = apply_rules(
+            chars,
+            &[
+                ("ational", "ate", m_gt_0 as fn(&[char]) -> bool),
+                ("tional", "tion", m_gt_0),
+                ("enci", "ence", m_gt_0),
+                ("anci", "ance", m_gt_0),
+                ("izer", "ize", m_gt_0),
+                ("abli", "able", m_gt_0),
+                ("alli", "al", m_gt_0),
+                ("entli", "ent", m_gt_0),
+                ("eli", "e", m_gt_0),
+                ("ousli", "ous", m_gt_0),
+                ("ization", "ize", m_gt_0),
+                ("ation", "ate", m_gt_0),
+                ("ator", "ate", m_gt_0),
+                ("alism", "al", m_gt_0),
+                ("iveness", "ive", m_gt_0),
+                ("fulness", "ful", m_gt_0),
+                ("ousness", "ous", m_gt_0),
+                ("aliti", "al", m_gt_0),
+                ("iviti", "ive", m_gt_0),
+                ("biliti", "ble", m_gt_0),
+            ],
+        );
+
+        // Step 3: further suffix reduction, conditioned on m > 0.
+        chars = apply_rules(
+            chars,
+            &[
+                ("icate", "ic", m_gt_0 as fn(&[char]) -> bool),
+                ("ative", "", m_gt_0),
+                ("alize", "al", m_gt_0),
+                ("iciti", "ic", m_gt_0),
+                ("ical", "ic", m_gt_0),
+                ("ful", "", m_gt_0),
+                ("ness", "", m_gt_0),
+            ],
+        );
+
+        // Step 4: drop common suffixes entirely once m > 1.
+        let step4_suffixes: [&str; 19] = [
+            "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent",
+            "ou", "ism", "ate", "iti", "ous", "ive", "ize", "ion",
+        ];
+        for suffix in step4_suffixes {
+            if ends_with(&chars, suffix) {
+                let stem = strip(&chars, suffix.chars().count());
+                let keep_ion = suffix == "ion"
+                    && matches!(stem.last(), Some(&'s') | Some(&'t'));
+                if measure(&stem) > 1 && (suffix != "ion" || keep_ion) {
+                    chars = stem;
+                }
+                break;
+            }
+        }
+
+        // Step 5a: remove a trailing "e" when m > 1, or m == 1 and the stem isn't *o.
+        if ends_with(&chars, "e") {
+            let stem = strip(&chars, 1);
+            let m = measure(&stem);
+            if m > 1 || (m == 1 && !ends_cvc(&stem)) {
+                chars = stem;
+            }
+        }
+
+        // Step 5b: collapse a trailing double "l" when m > 1.
+        if measure(&chars) > 1 && ends_double_consonant(&chars) && chars.last() == Some(&'l') {
+            chars.pop();
+        }
+
+        chars.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_porter_stems_plural_and_ing() {
+        let stemmer = PorterStemmer;
+        assert_eq!(stemmer.stem("running"), "run");
+        assert_eq!(stemmer.stem("flies"), "fli");
+        assert_eq!(stemmer.stem("cats"), "cat");
+    }
+
+    #[test]
+    fn test_porter_stems_ational_and_ation() {
+        // Step 2 turns "relational" -> "relate", and Step 5a's `(m>1) E ->` then strips
+        // that trailing `e` since `measure("relat")` > 1. Step 2 turns "generalization"
+        // -> "generalize", Step 3 turns that into "general", and Step 4 drops the "al"
+        // since `measure("gener")` > 1 — matching the literal 1980 Porter algorithm.
+        let stemmer = PorterStemmer;
+        assert_eq!(stemmer.stem("relational"), "relat");
+        assert_eq!(stemmer.stem("generalization"), "gener");
+    }
+
+    #[test]
+    fn test_porter_leaves_short_words_alone() {
+        let stemmer = PorterStemmer;
+        assert_eq!(stemmer.stem("is"), "is");
+    }
+
+    #[test]
+    fn test_noop_stemmer_is_identity() {
+        let stemmer = NoopStemmer;
+        assert_eq!(stemmer.stem("走る"), "走る");
+    }
+}